@@ -0,0 +1,54 @@
+//! Benchmarks for the German action, which is by far the most expensive one.
+//!
+//! Run with `cargo bench --bench german`.
+//!
+//! Word validity lookups are cached, so after the first iteration, these mostly measure
+//! the search for replacements itself, not the word list lookups.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use srgn::actions::{Action, German};
+
+static SENTENCES: &[(&str, &str)] = &[
+    (
+        "simple",
+        "Gruess Gott, wie geht es Ihnen? Mir geht es gut, danke der Nachfrage.",
+    ),
+    (
+        "umlauts",
+        "Zwoelf Boxkaempfer jagen Viktor quer ueber den grossen Sylter Deich.",
+    ),
+    (
+        "compounds",
+        "Du Suesswassertagtraeumer! Heizoelrueckstossabdaempfung ist schwierig.",
+    ),
+    (
+        "valid-alternatives",
+        "Abenteuer, Aerodynamik, Koeffizient, Mauer und Steuerung bleiben so.",
+    ),
+    (
+        // Many potential replacements in a single word blow up the power set.
+        "many-candidates",
+        "Aeuaeuaeuaeuaeu Ueberschuessiges Massstabsuebergreifendes Strassenschloesschen",
+    ),
+];
+
+fn german(c: &mut Criterion) {
+    let mut group = c.benchmark_group("german");
+
+    for (name, sentence) in SENTENCES {
+        for (mode, action) in [
+            ("default", German::default()),
+            ("prefer-original", German::new(true, false)),
+            ("naive", German::new(false, true)),
+        ] {
+            group.bench_with_input(BenchmarkId::new(mode, name), sentence, |b, input| {
+                b.iter(|| action.act(black_box(input)));
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, german);
+criterion_main!(benches);
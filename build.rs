@@ -1,9 +1,9 @@
 fn main() {
-    #[cfg(feature = "german")]
+    #[cfg(feature = "german-word-lists")]
     natural_languages::generate_word_lists();
 }
 
-#[cfg(feature = "german")]
+#[cfg(feature = "german-word-lists")]
 mod natural_languages {
     use std::io::{BufReader, BufWriter};
     use std::{
@@ -21,10 +21,10 @@ mod natural_languages {
 
         // Each of these might require different treatments, so do it separately.
 
-        {
-            // German
-            let source_file = base_source_path.join("de.txt");
-            let destination_file = base_destination_path.join("de.fst");
+        // German: the common word list, plus regional additions on top of it.
+        for name in ["de", "de-AT", "de-CH"] {
+            let source_file = base_source_path.join(format!("{name}.txt"));
+            let destination_file = base_destination_path.join(format!("{name}.fst"));
             destination_file.parent().map(fs::create_dir_all);
 
             german::process_german(
@@ -36,7 +36,7 @@ mod natural_languages {
         }
     }
 
-    #[cfg(feature = "german")]
+    #[cfg(feature = "german-word-lists")]
     mod german {
         use decompound::{decompound, DecompositionOptions};
         use rayon::prelude::*;
@@ -0,0 +1,46 @@
+//! Throws arbitrary input at the TypeScript scoper, for all premade queries.
+//!
+//! Malformed and partial source code is the norm here, so tree-sitter will produce
+//! plenty of error nodes. Scoping must not panic regardless, and the resulting scopes
+//! must cover the input exactly, in order.
+
+#![no_main]
+
+use clap::ValueEnum;
+use libfuzzer_sys::fuzz_target;
+use srgn::scoping::{
+    langs::{
+        typescript::{PremadeTypeScriptQuery, TypeScript, TypeScriptQuery},
+        LanguageScoper,
+    },
+    scope::ROScopes,
+    Scoper,
+};
+
+fuzz_target!(|data: &[u8]| {
+    // Scopers only ever see valid UTF-8.
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    for premade in PremadeTypeScriptQuery::value_variants() {
+        let lang = TypeScript::new(TypeScriptQuery::Premade(*premade));
+
+        let ranges = TypeScript::scope_via_query(&mut lang.query(), input);
+        for range in &ranges {
+            assert!(range.start <= range.end, "{premade:?}: inverted {range:?}");
+            assert!(
+                range.end <= input.len(),
+                "{premade:?}: out of bounds {range:?}"
+            );
+            assert!(
+                input.is_char_boundary(range.start) && input.is_char_boundary(range.end),
+                "{premade:?}: not on char boundary {range:?}"
+            );
+        }
+
+        let ROScopes(scopes) = lang.scope(input);
+        let reassembled: String = scopes.iter().map(<&str>::from).collect();
+        assert_eq!(reassembled, input, "{premade:?}: scopes do not cover input");
+    }
+});
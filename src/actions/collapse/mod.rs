@@ -0,0 +1,119 @@
+use super::Action;
+use log::info;
+
+/// The default maximum number of consecutive blank lines.
+const DEFAULT_MAX_BLANK_LINES: usize = 1;
+
+/// Collapses runs of consecutive blank lines.
+///
+/// A line is blank if it contains nothing but whitespace (spaces and tabs). Runs of
+/// more than the maximum number of consecutive blank lines are shortened to that
+/// maximum, keeping the first lines of each run as-is. A maximum of zero removes blank
+/// lines entirely.
+///
+/// Leading and trailing blank lines form runs like any other, and are collapsed, not
+/// removed. A line ending terminates its line, so the trailing newline of `"a\n"` is not
+/// followed by a blank line, while `"a\n\n"` ends in one. Line endings are otherwise
+/// left alone.
+///
+/// ## Example: normalizing vertical spacing
+///
+/// ```rust
+/// use srgn::actions::{Action, CollapseBlankLines};
+///
+/// let action = CollapseBlankLines::default();
+/// let result = action.act("a\n\n\n\nb\n\nc\n");
+///
+/// assert_eq!(result, "a\n\nb\n\nc\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollapseBlankLines {
+    max: usize,
+}
+
+impl CollapseBlankLines {
+    /// Create a new [`CollapseBlankLines`], allowing at most `max` consecutive blank
+    /// lines.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self { max }
+    }
+}
+
+impl Default for CollapseBlankLines {
+    /// Allows a single blank line.
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BLANK_LINES)
+    }
+}
+
+impl Action for CollapseBlankLines {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Collapsing blank lines of '{}' to at most {}",
+            input.escape_debug(),
+            self.max
+        );
+
+        let mut res = String::with_capacity(input.len());
+        let mut blanks = 0;
+
+        for line in input.split_inclusive('\n') {
+            if line.trim_matches([' ', '\t', '\r', '\n']).is_empty() {
+                blanks += 1;
+
+                if blanks > self.max {
+                    continue;
+                }
+            } else {
+                blanks = 0;
+            }
+
+            res.push_str(line);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", 1, "")]
+    #[case("a", 1, "a")]
+    #[case("a\n", 1, "a\n")]
+    #[case("a\nb\n", 1, "a\nb\n")]
+    //
+    // Collapsing
+    #[case("a\n\n\n\nb\n", 1, "a\n\nb\n")]
+    #[case("a\n\n\nb\n\n\nc", 1, "a\n\nb\n\nc")]
+    #[case("a\n\n\n\nb\n", 2, "a\n\n\nb\n")]
+    #[case("a\n\nb\n", 2, "a\n\nb\n")]
+    #[case("a\n\n\n\nb\n", 0, "a\nb\n")]
+    #[case("a\n\nb\n\nc\n", 0, "a\nb\nc\n")]
+    //
+    // Whitespace-only lines are blank, and kept as-is
+    #[case("a\n  \n\t\n \t \nb\n", 1, "a\n  \nb\n")]
+    #[case("a\n \nb", 5, "a\n \nb")]
+    //
+    // Leading and trailing runs are collapsed, not removed
+    #[case("\n\n\na\n", 1, "\na\n")]
+    #[case("a\n\n\n\n", 1, "a\n\n")]
+    #[case("a\n\n\n  ", 1, "a\n\n")]
+    #[case("\n\n\n", 1, "\n")]
+    #[case("\n\n\n", 0, "")]
+    //
+    // Line endings are kept
+    #[case("a\r\n\r\n\r\n\r\nb\r\n", 1, "a\r\n\r\nb\r\n")]
+    #[case("a\r\n \r\n\n\r\nb", 1, "a\r\n \r\nb")]
+    fn test_collapse_blank_lines(#[case] input: &str, #[case] max: usize, #[case] expected: &str) {
+        let result = CollapseBlankLines::new(max).act(input);
+
+        assert_eq!(result, expected);
+    }
+}
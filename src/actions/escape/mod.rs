@@ -0,0 +1,261 @@
+use super::Action;
+use clap::ValueEnum;
+use log::info;
+use std::fmt::Write;
+
+/// The syntax to [`Escape`] or [`Unescape`] for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum EscapeMode {
+    /// Contents of a JSON string: quotes, backslashes and control characters.
+    #[default]
+    Json,
+    /// Contents of a double-quoted shell string: `"`, `\`, `$` and `` ` ``.
+    Shell,
+    /// A regular expression matching the input literally: all metacharacters.
+    Regex,
+}
+
+/// Characters with a special meaning in regular expressions.
+const REGEX_METACHARACTERS: &[char] = &[
+    '\\', '.', '+', '*', '?', '(', ')', '|', '[', ']', '{', '}', '^', '$', '#', '&', '-', '~',
+];
+
+/// Characters with a special meaning inside double-quoted shell strings.
+const SHELL_METACHARACTERS: &[char] = &['"', '\\', '$', '`'];
+
+/// Escapes input, for use inside of strings of some syntax.
+///
+/// Intended for contents of strings, without their quotes: scope string contents only,
+/// not the surrounding quotes (which would otherwise be escaped as well). The inverse
+/// is [`Unescape`].
+///
+/// ## Example: escaping for JSON
+///
+/// ```rust
+/// use srgn::actions::{Action, Escape, EscapeMode};
+///
+/// let action = Escape::new(EscapeMode::Json);
+/// let result = action.act("say \"hi\"\nnow");
+///
+/// assert_eq!(result, r#"say \"hi\"\nnow"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Escape {
+    mode: EscapeMode,
+}
+
+impl Escape {
+    /// Create a new [`Escape`], escaping for the syntax of `mode`.
+    #[must_use]
+    pub fn new(mode: EscapeMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Action for Escape {
+    fn act(&self, input: &str) -> String {
+        info!("Escaping '{}' via {:?}", input.escape_debug(), self.mode);
+
+        let mut res = String::with_capacity(input.len());
+
+        for c in input.chars() {
+            match self.mode {
+                EscapeMode::Json => match c {
+                    '"' => res.push_str(r#"\""#),
+                    '\\' => res.push_str(r"\\"),
+                    '\n' => res.push_str(r"\n"),
+                    '\r' => res.push_str(r"\r"),
+                    '\t' => res.push_str(r"\t"),
+                    '\u{08}' => res.push_str(r"\b"),
+                    '\u{0C}' => res.push_str(r"\f"),
+                    c if u32::from(c) < 0x20 => {
+                        write!(res, r"\u{:04x}", u32::from(c))
+                            .expect("writing to string cannot fail");
+                    }
+                    c => res.push(c),
+                },
+                EscapeMode::Shell => {
+                    if SHELL_METACHARACTERS.contains(&c) {
+                        res.push('\\');
+                    }
+                    res.push(c);
+                }
+                EscapeMode::Regex => {
+                    if REGEX_METACHARACTERS.contains(&c) {
+                        res.push('\\');
+                    }
+                    res.push(c);
+                }
+            }
+        }
+
+        res
+    }
+}
+
+/// Unescapes input, the inverse of [`Escape`].
+///
+/// Only escape sequences valid in the respective syntax are unescaped. Anything else,
+/// such as `\d` in a regular expression or an incomplete `\u` sequence in JSON, is
+/// left as-is.
+///
+/// ## Example: unescaping from JSON
+///
+/// ```rust
+/// use srgn::actions::{Action, Unescape, EscapeMode};
+///
+/// let action = Unescape::new(EscapeMode::Json);
+/// let result = action.act(r#"say \"hi\"\nnow \u00e4"#);
+///
+/// assert_eq!(result, "say \"hi\"\nnow ä");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Unescape {
+    mode: EscapeMode,
+}
+
+impl Unescape {
+    /// Create a new [`Unescape`], unescaping from the syntax of `mode`.
+    #[must_use]
+    pub fn new(mode: EscapeMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Action for Unescape {
+    fn act(&self, input: &str) -> String {
+        info!("Unescaping '{}' via {:?}", input.escape_debug(), self.mode);
+
+        let mut res = String::with_capacity(input.len());
+        let mut rest = input;
+
+        while let Some(i) = rest.find('\\') {
+            res.push_str(&rest[..i]);
+            rest = &rest[i..];
+
+            let escaped = rest[1..].chars().next();
+            let (unescaped, len) = match (self.mode, escaped) {
+                (EscapeMode::Json, Some('"')) => ('"', 2),
+                (EscapeMode::Json, Some('\\')) => ('\\', 2),
+                (EscapeMode::Json, Some('/')) => ('/', 2),
+                (EscapeMode::Json, Some('n')) => ('\n', 2),
+                (EscapeMode::Json, Some('r')) => ('\r', 2),
+                (EscapeMode::Json, Some('t')) => ('\t', 2),
+                (EscapeMode::Json, Some('b')) => ('\u{08}', 2),
+                (EscapeMode::Json, Some('f')) => ('\u{0C}', 2),
+                (EscapeMode::Json, Some('u')) => unescape_json_unicode(rest).unwrap_or(('\\', 1)),
+                (EscapeMode::Shell, Some(c)) if SHELL_METACHARACTERS.contains(&c) => (c, 2),
+                (EscapeMode::Regex, Some(c)) if REGEX_METACHARACTERS.contains(&c) => (c, 2),
+                _ => ('\\', 1),
+            };
+
+            res.push(unescaped);
+            rest = &rest[len..];
+        }
+
+        res.push_str(rest);
+        res
+    }
+}
+
+/// Unescapes a leading JSON `\uXXXX` sequence of `input`, including surrogate pairs
+/// spanning two such sequences. Returns the character and the length of its escape
+/// sequence, in bytes.
+fn unescape_json_unicode(input: &str) -> Option<(char, usize)> {
+    fn code_unit(input: &str) -> Option<u16> {
+        let hex = input.strip_prefix(r"\u")?.get(..4)?;
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u16::from_str_radix(hex, 16).ok()
+    }
+
+    let high = code_unit(input)?;
+    if let Some(c) = char::from_u32(u32::from(high)) {
+        return Some((c, 6));
+    }
+
+    let low = code_unit(&input[6..])?;
+    let c = char::decode_utf16([high, low]).next()?.ok()?;
+
+    Some((c, 12))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // JSON
+    #[case(EscapeMode::Json, "", "")]
+    #[case(EscapeMode::Json, "abc", "abc")]
+    #[case(EscapeMode::Json, r#"say "hi""#, r#"say \"hi\""#)]
+    #[case(EscapeMode::Json, "a\nb\r\nc\td", r"a\nb\r\nc\td")]
+    #[case(EscapeMode::Json, r"C:\dir", r"C:\\dir")]
+    #[case(EscapeMode::Json, "\u{0}\u{1f}\u{08}\u{0C}", r"\u0000\u001f\b\f")]
+    #[case(EscapeMode::Json, "äöü 🦀 /", "äöü 🦀 /")]
+    //
+    // Shell
+    #[case(EscapeMode::Shell, r#"echo "$HOME""#, r#"echo \"\$HOME\""#)]
+    #[case(EscapeMode::Shell, r"a\b `c`", r"a\\b \`c\`")]
+    #[case(EscapeMode::Shell, "it's\n", "it's\n")]
+    //
+    // Regex
+    #[case(EscapeMode::Regex, "a.b*c", r"a\.b\*c")]
+    #[case(EscapeMode::Regex, "(x|y)?", r"\(x\|y\)\?")]
+    #[case(EscapeMode::Regex, r"[0-9]{2}\d", r"\[0\-9\]\{2\}\\d")]
+    #[case(EscapeMode::Regex, "^$#&~ plain", r"\^\$\#\&\~ plain")]
+    fn test_escape(#[case] mode: EscapeMode, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(Escape::new(mode).act(input), expected);
+    }
+
+    #[rstest]
+    // JSON
+    #[case(EscapeMode::Json, "", "")]
+    #[case(EscapeMode::Json, r#"say \"hi\""#, r#"say "hi""#)]
+    #[case(EscapeMode::Json, r"a\nb\r\nc\td", "a\nb\r\nc\td")]
+    #[case(EscapeMode::Json, r"C:\\dir \/", r"C:\dir /")]
+    #[case(EscapeMode::Json, r"\u00e4\u00C4", "äÄ")]
+    #[case(EscapeMode::Json, r"\ud83e\udd80", "🦀")] // Surrogate pair
+    //
+    // Invalid sequences are kept
+    #[case(EscapeMode::Json, r"\x", r"\x")]
+    #[case(EscapeMode::Json, r"\u12", r"\u12")]
+    #[case(EscapeMode::Json, r"\uzzzz", r"\uzzzz")]
+    #[case(EscapeMode::Json, r"\ud83e", r"\ud83e")] // Lone surrogate
+    #[case(EscapeMode::Json, r"\ud83e\u0041", r"\ud83eA")]
+    #[case(EscapeMode::Json, "\\", "\\")]
+    #[case(EscapeMode::Json, r"ä\", r"ä\")]
+    //
+    // Shell
+    #[case(EscapeMode::Shell, r#"echo \"\$HOME\""#, r#"echo "$HOME""#)]
+    #[case(EscapeMode::Shell, r"a\\b \`c\`", r"a\b `c`")]
+    #[case(EscapeMode::Shell, r"\n", r"\n")]
+    //
+    // Regex
+    #[case(EscapeMode::Regex, r"a\.b\*c", "a.b*c")]
+    #[case(EscapeMode::Regex, r"\d+\.\d+", r"\d+.\d+")]
+    fn test_unescape(#[case] mode: EscapeMode, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(Unescape::new(mode).act(input), expected);
+    }
+
+    #[rstest]
+    fn test_escape_roundtrip(
+        #[values(EscapeMode::Json, EscapeMode::Shell, EscapeMode::Regex)] mode: EscapeMode,
+        #[values(
+            "",
+            "plain",
+            "She said: \"it's $5 (or \\more\\)\"\n\tTabbed `cmd`.",
+            "\u{0}\u{7f} äöü 🦀 [x]{1,2}^$"
+        )]
+        input: &str,
+    ) {
+        let escaped = Escape::new(mode).act(input);
+
+        assert_eq!(Unescape::new(mode).act(&escaped), input);
+    }
+}
@@ -0,0 +1,262 @@
+use super::{Action, ActionError};
+use log::{error, info};
+use std::{
+    error::Error,
+    fmt,
+    io::Write,
+    process::{Command, Stdio},
+    thread,
+};
+
+/// Pipes input through an external command, replacing it with the command's output.
+///
+/// Much like `sed`'s `e` command, the command is run once per call (so once per part
+/// of scope), with the input fed to its standard input. Its standard output, taken
+/// verbatim (including any trailing newline), is the result. Standard error is not
+/// part of the result.
+///
+/// ## Failure
+///
+/// Running the command can fail: it might not exist, exit unsuccessfully, or produce
+/// output which is not valid UTF-8. As an [`Action`] cannot fail, the input is then
+/// returned unchanged and an error is logged. Use [`Exec::try_act`] (or
+/// [`Action::act_checked`]) to handle failures instead.
+///
+/// ## Example: uppercasing via `tr`
+///
+/// ```rust
+/// # #[cfg(unix)]
+/// # {
+/// use srgn::actions::Exec;
+///
+/// let action = Exec::new("tr", ["a-z", "A-Z"]);
+/// let result = action.try_act("hello").unwrap();
+///
+/// assert_eq!(result, "HELLO");
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exec {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Exec {
+    /// Create a new [`Exec`], running `program` with `args`.
+    #[must_use]
+    pub fn new(
+        program: impl Into<String>,
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            program: program.into(),
+            args: args.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Create a new [`Exec`], running `command` through the system shell (`sh -c` on
+    /// Unix, `cmd /C` on Windows).
+    #[must_use]
+    pub fn shell(command: impl Into<String>) -> Self {
+        if cfg!(windows) {
+            Self::new("cmd", ["/C".to_string(), command.into()])
+        } else {
+            Self::new("sh", ["-c".to_string(), command.into()])
+        }
+    }
+
+    /// Run the command on the given input, failing if it cannot be run or does not
+    /// succeed.
+    ///
+    /// # Errors
+    ///
+    /// If the command cannot be spawned or communicated with, exits unsuccessfully, or
+    /// its output is not valid UTF-8.
+    ///
+    /// # Panics
+    ///
+    /// Never, as the command's standard streams are always piped.
+    pub fn try_act(&self, input: &str) -> Result<String, ExecError> {
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| ExecError::Io(format!("failed to run '{}': {e}", self.program)))?;
+
+        let mut stdin = child.stdin.take().expect("stdin to be piped");
+
+        // Written from a separate thread, as the command might fill up its output pipe
+        // before having consumed all of its input, deadlocking.
+        let output = thread::scope(|s| {
+            let writer = s.spawn(move || stdin.write_all(input.as_bytes()));
+            let output = child.wait_with_output();
+
+            // A command not reading its input (entirely) is fine.
+            let _ = writer.join().expect("writer thread not to panic");
+
+            output
+        })
+        .map_err(|e| {
+            ExecError::Io(format!(
+                "failed to communicate with '{}': {e}",
+                self.program
+            ))
+        })?;
+
+        if !output.status.success() {
+            return Err(ExecError::Failed {
+                command: self.to_string(),
+                code: output.status.code(),
+                stderr: String::from_utf8_lossy(&output.stderr)
+                    .trim_end()
+                    .to_owned(),
+            });
+        }
+
+        String::from_utf8(output.stdout).map_err(|_| ExecError::InvalidUtf8(self.to_string()))
+    }
+}
+
+impl fmt::Display for Exec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.program)?;
+
+        for arg in &self.args {
+            write!(f, " {arg}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Action for Exec {
+    fn act(&self, input: &str) -> String {
+        info!("Running '{}' on '{}'", self, input.escape_debug());
+
+        self.try_act(input).unwrap_or_else(|e| {
+            error!("{e}; leaving input unchanged");
+            input.to_owned()
+        })
+    }
+
+    fn act_checked(&self, input: &str) -> Result<String, ActionError> {
+        info!("Running '{}' on '{}'", self, input.escape_debug());
+
+        self.try_act(input).map_err(Into::into)
+    }
+}
+
+/// An error that can occur when [running a command](Exec::try_act).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecError {
+    /// The command could not be spawned, or communicated with.
+    Io(String),
+    /// The command exited unsuccessfully.
+    Failed {
+        /// The command which failed.
+        command: String,
+        /// The exit code, if any (there is none if terminated by a signal).
+        code: Option<i32>,
+        /// What the command wrote to its standard error.
+        stderr: String,
+    },
+    /// The output of the command is not valid UTF-8.
+    InvalidUtf8(String),
+}
+
+impl fmt::Display for ExecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(msg) => write!(f, "Command error: {msg}"),
+            Self::Failed {
+                command,
+                code,
+                stderr,
+            } => {
+                match code {
+                    Some(code) => write!(f, "Command '{command}' failed with exit code {code}")?,
+                    None => write!(f, "Command '{command}' was terminated")?,
+                }
+
+                if !stderr.is_empty() {
+                    write!(f, ": {stderr}")?;
+                }
+
+                Ok(())
+            }
+            Self::InvalidUtf8(command) => {
+                write!(f, "Output of command '{command}' is not valid UTF-8")
+            }
+        }
+    }
+}
+
+impl Error for ExecError {}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Exec::new("tr", ["a-z", "A-Z"]), "hello, world\n", "HELLO, WORLD\n")]
+    #[case(Exec::new("tr", ["a-z", "A-Z"]), "", "")]
+    #[case(Exec::new("cat", Vec::<String>::new()), "Grüß 👋", "Grüß 👋")]
+    #[case(Exec::shell("tr a-z A-Z"), "hello", "HELLO")]
+    #[case(Exec::shell("printf x"), "ignored", "x")] // Input need not be read
+    fn test_exec(#[case] exec: Exec, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(exec.try_act(input).unwrap(), expected);
+        assert_eq!(exec.act(input), expected);
+    }
+
+    #[test]
+    fn test_exec_large_input() {
+        // Larger than typical pipe buffers, in both directions.
+        let input = "a".repeat(1 << 20);
+
+        let result = Exec::new("tr", ["a", "b"]).try_act(&input).unwrap();
+
+        assert_eq!(result, "b".repeat(1 << 20));
+    }
+
+    #[rstest]
+    #[case(Exec::shell("exit 3"), Some(3), "")]
+    #[case(Exec::shell("echo oops >&2; false"), Some(1), "oops")]
+    fn test_exec_failure(
+        #[case] exec: Exec,
+        #[case] expected_code: Option<i32>,
+        #[case] expected_stderr: &str,
+    ) {
+        let Err(ExecError::Failed { code, stderr, .. }) = exec.try_act("input") else {
+            panic!("Expected command to fail");
+        };
+
+        assert_eq!(code, expected_code);
+        assert_eq!(stderr, expected_stderr);
+
+        // Lenient: leaves input unchanged
+        assert_eq!(exec.act("input"), "input");
+        assert!(exec.act_checked("input").is_err());
+    }
+
+    #[test]
+    fn test_exec_missing_program() {
+        let exec = Exec::new("srgn-surely-nonexistent-program", Vec::<String>::new());
+
+        assert!(matches!(exec.try_act("input"), Err(ExecError::Io(_))));
+    }
+
+    #[test]
+    fn test_exec_invalid_utf8() {
+        let exec = Exec::shell(r"printf '\377'");
+
+        assert!(matches!(
+            exec.try_act("input"),
+            Err(ExecError::InvalidUtf8(_))
+        ));
+    }
+}
@@ -1,17 +1,28 @@
 use crate::actions::{
     german::{
         machine::{StateMachine, Transition},
-        words::{Replace, Replacement, WordCasing},
+        words::{Replace, Replacement, SpecialCharacter, Umlaut, Word, WordCasing},
     },
     Action,
 };
 use cached::proc_macro::cached;
 use cached::SizedCache;
+use clap::ValueEnum;
 use decompound::{decompound, DecompositionOptions};
-use itertools::Itertools;
-use itertools::MinMaxResult::{MinMax, NoElements, OneElement};
+use itertools::{Either, Itertools};
+#[cfg(not(feature = "german-word-lists"))]
+use log::warn;
 use log::{debug, trace};
+#[cfg(feature = "german-word-lists")]
 use once_cell::sync::Lazy;
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashSet},
+    fmt, fs, io,
+    ops::Range,
+    path::Path,
+    sync::Arc,
+};
 use unicode_titlecase::StrTitleCase;
 
 /// German language action, responsible for Umlauts and Eszett.
@@ -42,6 +53,19 @@ use unicode_titlecase::StrTitleCase;
 /// assert_eq!(result, "Grüß Gott!");
 /// ```
 ///
+/// # Example: Words attached to digits
+///
+/// Only letters make up words. Digits separate words just like whitespace or
+/// punctuation does, so letters attached to digits are still corrected on their own.
+///
+/// ```
+/// use srgn::actions::{Action, German};
+///
+/// let action = German::default();
+/// let result = action.act("Strasse1, 3Gruesse, B2B-Geschaeft");
+/// assert_eq!(result, "Straße1, 3Grüße, B2B-Geschäft");
+/// ```
+///
 /// # Example: A compound word
 ///
 /// Note that this compound word is *not* part of the word list (that would be an
@@ -244,14 +268,28 @@ use unicode_titlecase::StrTitleCase;
 /// - not available statically, aka at compile time, aka incurring a runtime cost. This
 ///   crate's binary is optimized for start-up speed.
 ///
+/// The word lists are compiled in with the (default) `german-word-lists` feature. Where
+/// binary size matters more, disable it and [load a word list at
+/// runtime](German::from_wordlist_path) instead.
+///
 /// For more info, an overview of the methods tried
 /// ([`phf`](https://crates.io/crates/phf) and more), and benchmarks, see [this
 /// issue](https://github.com/alexpovel/srgn/issues/9) and [this
 /// thread](https://users.rust-lang.org/t/fast-string-lookup-in-a-single-str-containing-millions-of-unevenly-sized-substrings/98040).
-#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)] // Independent options, see their setters.
+#[derive(Debug, Clone)]
 pub struct German {
     prefer_original: bool,
     naive: bool,
+    min_word_len: usize,
+    eszett_rule: bool,
+    never_correct: HashSet<String>,
+    name_heuristic: bool,
+    region: Region,
+    fuzzy: bool,
+    historical: bool,
+    enabled_replacements: HashSet<ReplacementKind>,
+    word_list: Option<Arc<fst::Set<Vec<u8>>>>,
 }
 
 impl German {
@@ -260,12 +298,12 @@ impl German {
     /// # Arguments
     ///
     /// * `prefer_original`: For a tied situation, where an original word and some
-    /// replacement are *both* legal, controls which one is returned. See
-    /// [below](#example-words-valid-both-in-original-and-replaced-form) for when this
-    /// is relevant.
+    ///   replacement are *both* legal, controls which one is returned. See
+    ///   [below](#example-words-valid-both-in-original-and-replaced-form) for when this
+    ///   is relevant.
     /// * `naive`: If `true`, perform any possible replacement, regardless of legality
-    /// of the resulting word. Useful for names, which are otherwise not modifiable as
-    /// they do not occur in dictionaries. See [example](#example-naive-mode).
+    ///   of the resulting word. Useful for names, which are otherwise not modifiable as
+    ///   they do not occur in dictionaries. See [example](#example-naive-mode).
     ///
     /// ## Example: Words valid both in original and replaced form
     ///
@@ -346,9 +384,61 @@ impl German {
         Self {
             prefer_original,
             naive,
+            min_word_len: 1,
+            eszett_rule: false,
+            never_correct: HashSet::new(),
+            name_heuristic: false,
+            region: Region::default(),
+            fuzzy: false,
+            historical: false,
+            enabled_replacements: ReplacementKind::value_variants().iter().copied().collect(),
+            word_list: None,
         }
     }
 
+    /// Create a new [`German`] with default settings, validating words against the
+    /// word list at `path` instead of the compiled-in ones.
+    ///
+    /// The file is plain text, containing one word per line. Words are looked up the
+    /// same way as for the compiled-in word lists, so compound words need not be
+    /// listed if their components are. Regional vocabulary does not apply, but
+    /// [`Region::Switzerland`] still never uses `ß`.
+    ///
+    /// Lookups in the compiled-in word lists are cached globally. Lookups in a list
+    /// loaded this way are not, so repeated words cost a lookup each time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    /// use std::io::Write;
+    ///
+    /// let mut file = tempfile::NamedTempFile::new().unwrap();
+    /// write!(file, "Fuß\nBall\n").unwrap();
+    ///
+    /// let action = German::from_wordlist_path(file.path()).unwrap();
+    /// assert_eq!(action.act("Fussball auf der Strasse"), "Fußball auf der Strasse");
+    /// ```
+    pub fn from_wordlist_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+
+        // Sorted and deduplicated, as required to build the set.
+        let words: BTreeSet<&str> = contents
+            .lines()
+            .map(str::trim)
+            .filter(|word| !word.is_empty())
+            .collect();
+        let set = fst::Set::from_iter(words)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(Self {
+            word_list: Some(Arc::new(set)),
+            ..Self::default()
+        })
+    }
+
     /// Prefer the original word over any replacement.
     pub fn prefer_original(&mut self) -> &mut Self {
         self.prefer_original = true;
@@ -372,26 +462,511 @@ impl German {
         self.naive = false;
         self
     }
-}
 
-impl Default for German {
-    /// Create a new [`German`] with default settings.
+    /// Leave words shorter than `len` (in [`char`]s) untouched.
     ///
-    /// Default settings are such that features of this action are leveraged most.
-    fn default() -> Self {
-        let prefer_original = false;
-        let naive = false;
-        Self::new(prefer_original, naive)
+    /// Very short tokens, such as a lone `ss` or `ae`, are unlikely to be meaningful
+    /// German words. The default of `1` considers all words.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    ///
+    /// let mut action = German::default();
+    /// action.naive().min_word_len(3);
+    /// assert_eq!(action.act("ae Suess"), "ae Süß");
+    /// ```
+    pub fn min_word_len(&mut self, len: usize) -> &mut Self {
+        self.min_word_len = len;
+        self
     }
-}
 
-impl Action for German {
-    fn act(&self, input: &str) -> String {
+    /// Fall back to the spelling rule for `ß`/`ss` when the word list is silent.
+    ///
+    /// Per the [reform of
+    /// 1996](https://en.wikipedia.org/wiki/German_orthography_reform_of_1996), `ß`
+    /// follows long vowels and diphthongs (*Fuß*, *heißen*), `ss` follows short vowels
+    /// (*Fluss*, *Kuss*). If neither the original word nor any replacement is a known
+    /// word, `ss` is replaced by `ß` where it follows a long vowel or diphthong.
+    ///
+    /// ## Limitations
+    ///
+    /// Vowel length is not visible in spelling in general. Only diphthongs (`ai`,
+    /// `ei`, `au`, `eu`, `äu`), `ie` and doubled vowels (`aa`, `ee`, `oo`) are detected
+    /// as long. A single long vowel, as in *Fuß* or *groß*, looks exactly like a short
+    /// one, so such words are left to the word list. Umlauts are never inserted by this
+    /// fallback.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    ///
+    /// let mut action = German::default();
+    /// action.eszett_rule(true);
+    ///
+    /// // Made-up words, not in any word list
+    /// assert_eq!(action.act("Bleissel"), "Bleißel"); // Diphthong: long
+    /// assert_eq!(action.act("Blassel"), "Blassel"); // Short
+    /// ```
+    pub fn eszett_rule(&mut self, enabled: bool) -> &mut Self {
+        self.eszett_rule = enabled;
+        self
+    }
+
+    /// Never correct any of the given `words`, emitting them verbatim.
+    ///
+    /// Useful for proper nouns and brand names which happen to be valid words, or
+    /// would be replaced naively. Matching is case-insensitive. Replaces any
+    /// previously set words.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    /// use std::collections::HashSet;
+    ///
+    /// let mut action = German::default();
+    /// action.naive().never_correct(HashSet::from(["Boeing".to_string()]));
+    ///
+    /// assert_eq!(action.act("BOEING and Schroeder"), "BOEING and Schröder");
+    /// ```
+    pub fn never_correct(&mut self, words: HashSet<String>) -> &mut Self {
+        self.never_correct = words.into_iter().map(|word| word.to_lowercase()).collect();
+        self
+    }
+
+    /// Do not replace `ss` by `ß` in words looking like names.
+    ///
+    /// Surnames often keep `ss` where the common word has `ß`, as in *Strauss* (name)
+    /// versus *Strauß* (bouquet, ostrich). With this heuristic enabled, a capitalized
+    /// word directly following a title (*Herr*, *Frau*, *Dr.*, *Prof.*, ...) is
+    /// considered a name, and left without Eszett replacements. Umlauts are still
+    /// replaced.
+    ///
+    /// Capitalization alone is no indicator, as all German nouns are capitalized.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    ///
+    /// let mut action = German::default();
+    /// action.name_heuristic(true);
+    ///
+    /// assert_eq!(
+    ///     action.act("Herr Strauss kauft einen Strauss."),
+    ///     "Herr Strauss kauft einen Strauß."
+    /// );
+    /// ```
+    pub fn name_heuristic(&mut self, enabled: bool) -> &mut Self {
+        self.name_heuristic = enabled;
+        self
+    }
+
+    /// Use the word list of the given [`Region`].
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German, Region};
+    ///
+    /// let mut action = German::default();
+    /// assert_eq!(action.act("Im Jaenner"), "Im Jaenner");
+    ///
+    /// action.region(Region::Austria);
+    /// assert_eq!(action.act("Im Jaenner"), "Im Jänner");
+    /// ```
+    pub fn region(&mut self, region: Region) -> &mut Self {
+        self.region = region;
+        self
+    }
+
+    /// For words otherwise left unchanged, also try swapping adjacent characters, to
+    /// correct typos like *Breucke* (for *Bruecke*, hence *Brücke*).
+    ///
+    /// Only swaps of an `e` with a neighboring `a`, `o` or `u` are tried, and only
+    /// results containing an Umlaut and validated by the word list are accepted. Still,
+    /// this changes words beyond their spelling, so is off by default. Has no effect
+    /// in naive mode, which does not consult the word list.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    ///
+    /// let mut action = German::default();
+    /// assert_eq!(action.act("Breucke"), "Breucke");
+    ///
+    /// action.fuzzy(true);
+    /// assert_eq!(action.act("Breucke"), "Brücke");
+    /// ```
+    pub fn fuzzy(&mut self, enabled: bool) -> &mut Self {
+        self.fuzzy = enabled;
+        self
+    }
+
+    /// For words otherwise left unchanged, also try modernizing historical spellings,
+    /// as found in older texts.
+    ///
+    /// Full historical normalization is out of scope. Only this small set of common
+    /// spellings is mapped, in all casings (e.g. `Th`, `TH` and `th`):
+    ///
+    /// | Historical | Modern | Example                |
+    /// | ---------- | ------ | ---------------------- |
+    /// | `ſ`        | `s`    | *Waſſer* → *Wasser*    |
+    /// | `th`       | `t`    | *Thür* → *Tür*         |
+    /// | `ey`       | `ei`   | *seyn* → *sein*        |
+    /// | `dt`       | `t`    | *todt* → *tot*         |
+    ///
+    /// Only words unknown to the word list are considered, and only results validated
+    /// by it are accepted, so valid modern words such as *Theater* or *Stadt* are left
+    /// alone. Umlauts and Eszett are then replaced as usual. Has no effect in naive
+    /// mode, which does not consult the word list.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German};
+    ///
+    /// let mut action = German::default();
+    /// assert_eq!(action.act("Die Thaeler und das Theater"), "Die Thaeler und das Theater");
+    ///
+    /// action.historical(true);
+    /// assert_eq!(action.act("Die Thaeler und das Theater"), "Die Täler und das Theater");
+    /// ```
+    pub fn historical(&mut self, enabled: bool) -> &mut Self {
+        self.historical = enabled;
+        self
+    }
+
+    /// Only attempt replacements of the given kinds, leaving others alone. All kinds
+    /// are enabled by default.
+    ///
+    /// ```
+    /// use srgn::actions::{Action, German, ReplacementKind};
+    /// use std::collections::HashSet;
+    ///
+    /// let mut action = German::default();
+    /// action.enabled_replacements(HashSet::from([ReplacementKind::Eszett]));
+    ///
+    /// assert_eq!(action.act("Strasse Aepfel"), "Straße Aepfel");
+    /// ```
+    pub fn enabled_replacements(&mut self, kinds: HashSet<ReplacementKind>) -> &mut Self {
+        self.enabled_replacements = kinds;
+        self
+    }
+
+    /// Explain how the first word of `input` is processed.
+    ///
+    /// Useful for debugging why a word was or was not replaced: reports all candidate
+    /// spellings tried, in order, whether each was found valid, and the final result.
+    /// Returns [`None`] if `input` contains no word.
+    ///
+    /// ```
+    /// use srgn::actions::German;
+    ///
+    /// let explanation = German::default().explain("Fuss").unwrap();
+    ///
+    /// assert_eq!(explanation.word, "Fuss");
+    /// assert_eq!(explanation.result, "Fuß");
+    /// assert!(explanation.candidates.iter().any(|c| c.word == "Fuß" && c.valid));
+    /// ```
+    #[must_use]
+    pub fn explain(&self, input: &str) -> Option<Explanation> {
+        let mut machine = StateMachine::new();
+
+        for char in input.chars().chain(std::iter::once('\0')) {
+            if let Transition::Exited = machine.transition(char) {
+                let mut candidates = Vec::new();
+                let result =
+                    self.process_word(machine.current_word(), false, &mut |word, valid| {
+                        candidates.push(Candidate {
+                            word: word.to_owned(),
+                            valid,
+                        });
+                    });
+
+                return Some(Explanation {
+                    word: machine.current_word().content().to_owned(),
+                    candidates,
+                    result,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Correct a single, already tokenized `word`.
+    ///
+    /// Skips word detection, for use with an external tokenizer: `word` is expected to
+    /// be exactly one word, and corrected using all settings of this action. Input
+    /// which is not a single word (e.g. contains whitespace or punctuation) is returned
+    /// unchanged.
+    ///
+    /// ```
+    /// use srgn::actions::German;
+    ///
+    /// let action = German::default();
+    ///
+    /// assert_eq!(action.correct_word("Strasse"), "Straße");
+    /// assert_eq!(action.correct_word("Haus"), "Haus");
+    /// assert_eq!(action.correct_word("Strasse Strasse"), "Strasse Strasse");
+    /// ```
+    #[must_use]
+    pub fn correct_word<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        let mut machine = StateMachine::new();
+
+        for char in word.chars().chain(std::iter::once('\0')) {
+            if let Transition::Exited = machine.transition(char) {
+                let current = machine.current_word();
+
+                if current.content() != word {
+                    debug!("Input is not a single word, not correcting: {word:?}");
+                    break;
+                }
+
+                let result = self.process_word(current, false, &mut |_, _| {});
+
+                if result != word {
+                    return Cow::Owned(result);
+                }
+
+                break;
+            }
+        }
+
+        Cow::Borrowed(word)
+    }
+
+    /// Find the replacement for a single `word`, reporting each candidate tried (and
+    /// whether it was valid) to `on_candidate`. Words which are `name_like` receive no
+    /// Eszett replacements.
+    fn process_word(
+        &self,
+        word: &Word,
+        name_like: bool,
+        on_candidate: &mut impl FnMut(&str, bool),
+    ) -> String {
+        let original = word.content().to_owned();
+
+        if original.chars().count() < self.min_word_len {
+            debug!("Word shorter than minimum length, skipping: {original:?}");
+            return original;
+        }
+
+        if self.never_correct.contains(&original.to_lowercase()) {
+            debug!("Word is on exception list, skipping: {original:?}");
+            return original;
+        }
+
+        let replacements = self.replacements(word, name_like);
+
+        find_valid_replacement(
+            &original,
+            &replacements,
+            self.prefer_original,
+            self.naive,
+            &self.word_list(),
+            on_candidate,
+        )
+        .or_else(|| {
+            if self.eszett_rule {
+                apply_eszett_rule(&original, &replacements, &self.word_list())
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            if self.historical && !self.naive {
+                self.find_valid_modernization(&original, name_like, on_candidate)
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            if self.fuzzy && !self.naive {
+                self.find_valid_transposition(&original, name_like, on_candidate)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(original)
+    }
+
+    /// The word list to validate words against.
+    fn word_list(&self) -> WordList {
+        match &self.word_list {
+            Some(set) => WordList::Custom(Arc::clone(set)),
+            None => WordList::Embedded(self.region),
+        }
+    }
+
+    /// The replacements of `word` to consider: only enabled kinds, and without Eszett
+    /// ones where unwanted.
+    fn replacements<'a>(&self, word: &'a Word, name_like: bool) -> Cow<'a, [Replacement]> {
+        let keep_eszett = !(name_like || self.region == Region::Switzerland);
+        if !keep_eszett {
+            debug!("Word looks like a name or region lacks Eszett, keeping 'ss': {word:?}");
+        }
+
+        let is_wanted = |replacement: &Replacement| {
+            let kind = ReplacementKind::of(*replacement.content());
+
+            self.enabled_replacements.contains(&kind)
+                && (keep_eszett || kind != ReplacementKind::Eszett)
+        };
+
+        if word.replacements().iter().all(is_wanted) {
+            word.replacements().into()
+        } else {
+            word.replacements()
+                .iter()
+                .filter(|r| is_wanted(r))
+                .cloned()
+                .collect_vec()
+                .into()
+        }
+    }
+
+    /// Try swapping an `e` with a neighboring `a`, `o` or `u`, accepting the first
+    /// such swap which yields a valid word containing a replacement (see
+    /// [`German::fuzzy`]).
+    fn find_valid_transposition(
+        &self,
+        original: &str,
+        name_like: bool,
+        on_candidate: &mut impl FnMut(&str, bool),
+    ) -> Option<String> {
+        let word_list = self.word_list();
+
+        if word_list.is_valid(original) {
+            // Not a typo to begin with.
+            return None;
+        }
+
+        let chars = original.chars().collect_vec();
+
+        chars
+            .iter()
+            .tuple_windows()
+            .positions(|(a, b)| is_transposable(*a, *b))
+            .find_map(|i| {
+                let mut swapped = chars.clone();
+                swapped.swap(i, i + 1);
+                let swapped: String = swapped.into_iter().collect();
+                debug!("Trying transposition {swapped:?} of {original:?}");
+
+                let mut machine = StateMachine::new();
+                for char in swapped.chars().chain(std::iter::once('\0')) {
+                    machine.transition(char);
+                }
+                let word = machine.current_word();
+
+                let replacements = self.replacements(word, name_like);
+                if replacements.is_empty() {
+                    // The swap needs to be justified by a special character.
+                    return None;
+                }
+
+                find_valid_replacement(
+                    &swapped,
+                    &replacements,
+                    false,
+                    false,
+                    &word_list,
+                    on_candidate,
+                )
+                .filter(|result| result != &swapped)
+            })
+    }
+
+    /// Try replacing historical spellings by modern ones, accepting the first, smallest
+    /// set of such replacements which yields a valid word (see [`German::historical`]).
+    fn find_valid_modernization(
+        &self,
+        original: &str,
+        name_like: bool,
+        on_candidate: &mut impl FnMut(&str, bool),
+    ) -> Option<String> {
+        let word_list = self.word_list();
+
+        if word_list.is_valid(original) {
+            // Not historical to begin with.
+            return None;
+        }
+
+        let sites = historical_spellings(original);
+
+        sites
+            .iter()
+            .powerset()
+            .skip(1) // Empty set, the original
+            .find_map(|sites| {
+                let mut modernized = String::with_capacity(original.len());
+                let mut last = 0;
+                for (range, modern) in sites {
+                    modernized.push_str(&original[last..range.start]);
+                    modernized.push_str(modern);
+                    last = range.end;
+                }
+                modernized.push_str(&original[last..]);
+                debug!("Trying modernization {modernized:?} of {original:?}");
+
+                let mut machine = StateMachine::new();
+                for char in modernized.chars().chain(std::iter::once('\0')) {
+                    machine.transition(char);
+                }
+                let word = machine.current_word();
+
+                let replacements = self.replacements(word, name_like);
+
+                // The modernized word itself is tried first.
+                find_valid_replacement(
+                    &modernized,
+                    &replacements,
+                    true,
+                    false,
+                    &word_list,
+                    on_candidate,
+                )
+            })
+    }
+
+    /// Process `input` like [`Action::act`], additionally counting the replacements
+    /// applied, by their target character.
+    ///
+    /// Useful for analyzing a corpus. Only characters introduced by this action are
+    /// counted, no matter which rule introduced them; ones already present in the
+    /// input are not.
+    ///
+    /// ```
+    /// use srgn::actions::German;
+    ///
+    /// let (output, counts) = German::default().act_counting("Gruesse aus der Strasse");
+    ///
+    /// assert_eq!(output, "Grüße aus der Straße");
+    /// assert_eq!(counts.ue, 1);
+    /// assert_eq!(counts.eszett, 2);
+    /// assert_eq!(counts.total(), 3);
+    /// ```
+    #[must_use]
+    pub fn act_counting(&self, input: &str) -> (String, ReplacementCounts) {
+        let mut counts = ReplacementCounts::default();
+
+        let output = self.process(input, &mut |original, result| {
+            counts.record(original, result);
+        });
+
+        (output, counts)
+    }
+
+    /// Process all words of `input`, reporting each word and its result to
+    /// `on_word`.
+    fn process(&self, input: &str, on_word: &mut impl FnMut(&str, &str)) -> String {
         const INDICATOR: char = '\0';
 
         let mut output = String::with_capacity(input.len());
         let mut machine = StateMachine::new();
 
+        // Context for the name heuristic: the previous word, and what came after it.
+        let mut previous_word = String::new();
+        let mut gap = String::new();
+
+        // Invisible characters inside of words (e.g. soft hyphens in text copied from
+        // PDFs) are withheld from the machine, to be reinserted after processing. Kept
+        // alongside their byte offset into the word.
+        let mut invisibles: Vec<(usize, char)> = Vec::new();
+        let mut in_word = false;
+
         // The state machine, much like a missing trailing newline in a file, will
         // misbehave if the very last transition is not an 'external' one (the last word
         // won't be detected properly).
@@ -401,6 +976,13 @@ impl Action for German {
                 char.escape_debug()
             );
 
+            if in_word && is_invisible(char) {
+                let offset = machine.current_word().content().len();
+                trace!("Withholding invisible character at offset {offset} of word");
+                invisibles.push((offset, char));
+                continue;
+            }
+
             let transition = machine.transition(char);
 
             trace!("Transition is '{:?}'", transition);
@@ -408,22 +990,28 @@ impl Action for German {
             match transition {
                 Transition::External => {
                     output.push(char);
-                    continue;
+                    gap.push(char);
                 }
                 Transition::Entered | Transition::Internal => {
-                    continue;
+                    in_word = true;
                 }
                 Transition::Exited => {
                     debug!("Exited machine: {:?}", machine);
+                    in_word = false;
+
+                    let current = machine.current_word();
+                    let name_like = self.name_heuristic
+                        && is_name_like(current.content(), &previous_word, &gap);
+
+                    let word = self.process_word(current, name_like, &mut |_, _| {});
+                    on_word(current.content(), &word);
+                    let word = reinsert_invisibles(current.content(), &word, &invisibles);
+                    invisibles.clear();
 
-                    let original = machine.current_word().content().to_owned();
-                    let word = find_valid_replacement(
-                        &original,
-                        machine.current_word().replacements(),
-                        self.prefer_original,
-                        self.naive,
-                    )
-                    .unwrap_or(original);
+                    previous_word.clear();
+                    previous_word.push_str(current.content());
+                    gap.clear();
+                    gap.push(char);
 
                     debug!("Processed word, appending to output: {:?}", &word);
                     output.push_str(&word);
@@ -447,43 +1035,174 @@ impl Action for German {
     }
 }
 
+impl Default for German {
+    /// Create a new [`German`] with default settings.
+    ///
+    /// Default settings are such that features of this action are leveraged most.
+    fn default() -> Self {
+        let prefer_original = false;
+        let naive = false;
+        Self::new(prefer_original, naive)
+    }
+}
+
+impl Action for German {
+    fn act(&self, input: &str) -> String {
+        self.process(input, &mut |_, _| {})
+    }
+}
+
+/// How [`German`] processed a single word, see [`German::explain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    /// The word as found in the input.
+    pub word: String,
+    /// All candidate spellings tried, in order.
+    pub candidates: Vec<Candidate>,
+    /// The word as output.
+    pub result: String,
+}
+
+/// A candidate spelling tried for a word, see [`Explanation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    /// The candidate spelling.
+    pub word: String,
+    /// Whether the candidate was found to be valid.
+    pub valid: bool,
+}
+
+/// Replacements applied by [`German`], by target character (regardless of casing),
+/// see [`German::act_counting`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplacementCounts {
+    /// Number of `ä` (from `ae`).
+    pub ae: usize,
+    /// Number of `ö` (from `oe`).
+    pub oe: usize,
+    /// Number of `ü` (from `ue`).
+    pub ue: usize,
+    /// Number of `ß` (from `ss`).
+    pub eszett: usize,
+}
+
+impl ReplacementCounts {
+    /// The total number of replacements.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.ae + self.oe + self.ue + self.eszett
+    }
+
+    /// Count the special characters `result` gained over `original`.
+    fn record(&mut self, original: &str, result: &str) {
+        if original == result {
+            return;
+        }
+
+        let count =
+            |word: &str, targets: &[char]| word.chars().filter(|c| targets.contains(c)).count();
+        let gained =
+            |targets: &[char]| count(result, targets).saturating_sub(count(original, targets));
+
+        self.ae += gained(&['ä', 'Ä']);
+        self.oe += gained(&['ö', 'Ö']);
+        self.ue += gained(&['ü', 'Ü']);
+        self.eszett += gained(&['ß', 'ẞ']);
+    }
+}
+
+impl fmt::Display for ReplacementCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ä: {}, ö: {}, ü: {}, ß: {}",
+            self.ae, self.oe, self.ue, self.eszett
+        )
+    }
+}
+
+/// A kind of replacement performed by [`German`], see
+/// [`German::enabled_replacements`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum)]
+pub enum ReplacementKind {
+    /// `ae` to `ä`.
+    Ae,
+    /// `oe` to `ö`.
+    Oe,
+    /// `ue` to `ü`.
+    Ue,
+    /// `ss` to `ß`.
+    #[value(name = "ss")]
+    Eszett,
+}
+
+impl ReplacementKind {
+    /// The kind of replacement producing `character`, regardless of casing.
+    fn of(character: SpecialCharacter) -> Self {
+        match character {
+            SpecialCharacter::Umlaut(Umlaut::Ae(_)) => Self::Ae,
+            SpecialCharacter::Umlaut(Umlaut::Oe(_)) => Self::Oe,
+            SpecialCharacter::Umlaut(Umlaut::Ue(_)) => Self::Ue,
+            SpecialCharacter::Eszett(_) => Self::Eszett,
+        }
+    }
+}
+
+/// A region of the German language, with its own vocabulary.
+///
+/// Each region uses the common German word list, plus words specific to it (e.g.
+/// *Jänner* in Austria). All word lists are compiled in (see
+/// [`German::from_wordlist_path`] for an alternative).
+///
+/// The regional lists are small and hand-curated: a handful of common regionalisms
+/// containing Umlauts, written for this crate, not a comprehensive vocabulary. For
+/// full coverage of a region, [load a word list](German::from_wordlist_path) instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, ValueEnum)]
+pub enum Region {
+    /// Germany (`de-DE`): the common word list only.
+    #[default]
+    #[value(name = "de-DE")]
+    Germany,
+    /// Austria (`de-AT`).
+    #[value(name = "de-AT")]
+    Austria,
+    /// Switzerland (`de-CH`). Swiss Standard German does not use `ß`, so `ss` is
+    /// never replaced.
+    #[value(name = "de-CH")]
+    Switzerland,
+}
+
 fn find_valid_replacement(
     word: &str,
     replacements: &[Replacement],
     prefer_original: bool,
     naive: bool,
+    word_list: &WordList,
+    on_candidate: &mut impl FnMut(&str, bool),
 ) -> Option<String> {
-    let replacement_combinations = {
-        let mut res: Vec<Vec<_>> = replacements
-            .iter()
-            .powerset()
-            .map(|v| v.into_iter().cloned().collect())
-            .collect();
+    // Lazily iterated: for the common case, a valid candidate is found early on, and
+    // materializing the entire (exponentially large) power set would be wasted work.
+    let replacement_combinations = if naive {
+        // Only the shortest (empty) set is of interest, required later for
+        // `prefer_original`, and the longest one, containing *all* theoretically
+        // possible replacements.
+        let all = replacements.iter().collect_vec();
 
-        if naive {
-            // Removes all intermediate sets: the shortest (empty) set is required later
-            // for `prefer_original`. The longest contains *all* theoretically possible
-            // replacements
-            res = match res.into_iter().minmax_by_key(Vec::len) {
-                NoElements => {
-                    unreachable!("powerset always contains at least the empty set")
-                }
-                OneElement(e) => vec![e],
-                MinMax(min, max) => vec![min, max],
-            };
-        }
+        let combinations = if all.is_empty() {
+            vec![all]
+        } else {
+            vec![Vec::new(), all]
+        };
 
-        res
+        Either::Left(combinations.into_iter())
+    } else {
+        Either::Right(replacements.iter().powerset())
     };
 
     debug!("Starting search for valid replacement for word '{}'", word);
-    trace!(
-        "All replacement combinations to try: {:?}",
-        replacement_combinations
-    );
 
-    // By definition, the power set contains the empty set. There are two options for
-    // handling it:
+    // By definition, the power set contains the empty set, as its first element. There
+    // are two options for handling it:
     // - not skipping: empty set is tried first, and if that word is valid, it is
     //   returned
     // - skipping: empty set is skipped, *some* replacements will take place; if none of
@@ -493,22 +1212,27 @@ fn find_valid_replacement(
     // iteration. Then, `Busse` will turn out to be valid already and will be returned .
     // Skipping it means `Buße` is tried, which is *also* valid and returned, foregoing
     // `Busse`.
-    debug_assert!(replacement_combinations
-        .first()
-        .map_or(true, std::vec::Vec::is_empty));
-
     #[allow(clippy::bool_to_int_with_if)] // Readability is much better.
     let n_skip = if prefer_original { 0 } else { 1 };
 
-    for replacements in replacement_combinations.into_iter().skip(n_skip) {
-        let mut candidate = word.to_owned();
+    // Reused across candidates, saving an allocation for each.
+    let mut candidate = String::with_capacity(word.len());
+
+    for replacements in replacement_combinations.skip(n_skip) {
+        trace!("Trying replacement combination: {:?}", replacements);
+
+        candidate.clear();
+        candidate.push_str(word);
         candidate.apply_replacements(replacements);
         trace!(
             "Replaced candidate word, now is: '{}'. Starting validity check.",
             candidate
         );
 
-        if naive || is_valid(&candidate, &contained_in_global_word_list) {
+        let valid = naive || word_list.is_valid(&candidate);
+        on_candidate(&candidate, valid);
+
+        if valid {
             debug!("Candidate '{}' is valid, returning early", candidate);
             return Some(candidate);
         }
@@ -520,6 +1244,176 @@ fn find_valid_replacement(
     None
 }
 
+/// Replace `ss` by `ß` after long vowels and diphthongs, if the word list knows
+/// neither the original `word` nor any of its replacements.
+///
+/// See [`German::eszett_rule`].
+fn apply_eszett_rule(
+    word: &str,
+    replacements: &[Replacement],
+    word_list: &WordList,
+) -> Option<String> {
+    if word_list.is_valid(word) {
+        debug!(
+            "Original word '{}' is known, not applying Eszett rule",
+            word
+        );
+        return None;
+    }
+
+    let eszetts = replacements
+        .iter()
+        .filter(|r| matches!(r.content(), SpecialCharacter::Eszett(_)))
+        .filter(|r| ends_in_long_vowel(&word[..r.start()]))
+        .collect_vec();
+
+    if eszetts.is_empty() {
+        return None;
+    }
+
+    let mut candidate = word.to_owned();
+    candidate.apply_replacements(eszetts);
+    debug!("Applied Eszett rule, word is now '{}'", candidate);
+
+    Some(candidate)
+}
+
+/// Whether `word` is in a position typical for names: capitalized, and following a
+/// title (`previous_word`), separated from it by whitespace (`gap`), optionally after
+/// an abbreviating period.
+fn is_name_like(word: &str, previous_word: &str, gap: &str) -> bool {
+    const TITLES: &[&str] = &["herr", "herrn", "frau", "fräulein", "dr", "prof", "familie"];
+
+    let gap = gap.strip_prefix('.').unwrap_or(gap);
+
+    word.chars().next().is_some_and(char::is_uppercase)
+        && TITLES.contains(&previous_word.to_lowercase().as_str())
+        && !gap.is_empty()
+        && gap.chars().all(char::is_whitespace)
+}
+
+/// Historical spellings, with their modern counterparts. See [`German::historical`].
+const HISTORICAL_SPELLINGS: &[(&str, &str)] = &[
+    ("ſ", "s"),
+    ("th", "t"),
+    ("Th", "T"),
+    ("TH", "T"),
+    ("ey", "ei"),
+    ("Ey", "Ei"),
+    ("EY", "EI"),
+    ("dt", "t"),
+    ("DT", "T"),
+];
+
+/// The non-overlapping historical spellings found in `word`, as byte ranges along with
+/// their modern replacements, in order.
+fn historical_spellings(word: &str) -> Vec<(Range<usize>, &'static str)> {
+    let mut sites = HISTORICAL_SPELLINGS
+        .iter()
+        .flat_map(|(historical, modern)| {
+            word.match_indices(historical)
+                .map(|(start, m)| (start..start + m.len(), *modern))
+        })
+        .collect_vec();
+    sites.sort_by_key(|(range, _)| range.start);
+
+    let mut end = 0;
+    sites.retain(|(range, _)| {
+        let keep = range.start >= end;
+        if keep {
+            end = range.end;
+        }
+        keep
+    });
+
+    sites
+}
+
+/// Whether swapping `a` and `b` might correct a typo in an Umlaut spelling, as in
+/// *Breucke* for *Bruecke*.
+fn is_transposable(a: char, b: char) -> bool {
+    let is_e = |c: char| matches!(c, 'e' | 'E');
+    let is_base = |c: char| matches!(c, 'a' | 'o' | 'u' | 'A' | 'O' | 'U');
+
+    (is_e(a) && is_base(b)) || (is_base(a) && is_e(b))
+}
+
+/// Whether `preceding` ends in a (detectably) long vowel or diphthong.
+fn ends_in_long_vowel(preceding: &str) -> bool {
+    let mut tail = preceding.chars().rev().take(2).collect_vec();
+    tail.reverse();
+    let tail = tail.into_iter().collect::<String>().to_lowercase();
+
+    matches!(
+        tail.as_str(),
+        "ai" | "ei" | "au" | "eu" | "äu" | "ie" | "aa" | "ee" | "oo"
+    )
+}
+
+/// Whether `c` is invisible, such that it can occur inside of words without breaking
+/// them apart (soft hyphens, zero-width spaces and joiners, ...).
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' | '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+    )
+}
+
+/// Reinserts `invisibles`, located by byte offset into `original`, into `processed`.
+///
+/// `processed` is `original` with some character pairs (`ae`, `ss`, ...) replaced by a
+/// single special character each. An invisible character located in the middle of such
+/// a pair ends up after its replacement.
+fn reinsert_invisibles(original: &str, processed: &str, invisibles: &[(usize, char)]) -> String {
+    if invisibles.is_empty() {
+        return processed.to_owned();
+    }
+
+    let mut res = String::with_capacity(processed.len() + invisibles.len() * 3);
+    let mut invisibles = invisibles.iter().peekable();
+    let mut original = original.char_indices().peekable();
+
+    for c in processed.chars() {
+        if let Some(&(pos, o)) = original.peek() {
+            while let Some((_, invisible)) = invisibles.next_if(|(offset, _)| *offset <= pos) {
+                res.push(*invisible);
+            }
+
+            original.next();
+            if c != o {
+                // A replacement, which spans two original characters.
+                original.next();
+            }
+        }
+
+        res.push(c);
+    }
+
+    res.extend(invisibles.map(|(_, invisible)| invisible));
+    res
+}
+
+/// A word list to validate words against.
+#[allow(variant_size_differences)] // Short-lived, and a pointer at most.
+#[derive(Debug, Clone)]
+enum WordList {
+    /// The compiled-in word lists of a region.
+    Embedded(Region),
+    /// A word list loaded at runtime, see [`German::from_wordlist_path`].
+    Custom(Arc<fst::Set<Vec<u8>>>),
+}
+
+impl WordList {
+    fn is_valid(&self, word: &str) -> bool {
+        match self {
+            Self::Embedded(region) => is_valid(word, *region),
+            // Not cached, as the cache is global, but lists are not.
+            Self::Custom(set) => is_valid_with(word, &|w: &str| set.contains(w)),
+        }
+    }
+}
+
+#[cfg(feature = "german-word-lists")]
 static SET: Lazy<fst::Set<&[u8]>> = Lazy::new(|| {
     let bytes: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), "/de.fst")); // Generated in `build.rs`.
     trace!("Loading FST.");
@@ -528,6 +1422,19 @@ static SET: Lazy<fst::Set<&[u8]>> = Lazy::new(|| {
     set
 });
 
+#[cfg(feature = "german-word-lists")]
+static SET_AT: Lazy<fst::Set<&[u8]>> = Lazy::new(|| {
+    let bytes: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), "/de-AT.fst")); // Generated in `build.rs`.
+    fst::Set::new(bytes).expect("Failed to load FST; FST bytes malformed at build time?")
+});
+
+#[cfg(feature = "german-word-lists")]
+static SET_CH: Lazy<fst::Set<&[u8]>> = Lazy::new(|| {
+    let bytes: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), "/de-CH.fst")); // Generated in `build.rs`.
+    fst::Set::new(bytes).expect("Failed to load FST; FST bytes malformed at build time?")
+});
+
+#[cfg(feature = "german-word-lists")]
 fn contained_in_global_word_list(word: &str) -> bool {
     trace!("Performing lookup of '{word}' in FST.");
     let result = SET.contains(word);
@@ -536,13 +1443,45 @@ fn contained_in_global_word_list(word: &str) -> bool {
     result
 }
 
+#[cfg(feature = "german-word-lists")]
+fn contained_in_regional_word_list(word: &str, region: Region) -> bool {
+    contained_in_global_word_list(word)
+        || match region {
+            Region::Germany => false,
+            Region::Austria => SET_AT.contains(word),
+            Region::Switzerland => SET_CH.contains(word),
+        }
+}
+
+#[cfg(not(feature = "german-word-lists"))]
+fn contained_in_regional_word_list(_word: &str, _region: Region) -> bool {
+    static WARNING: std::sync::Once = std::sync::Once::new();
+
+    WARNING.call_once(|| {
+        warn!(
+            "No German word lists compiled in (feature `german-word-lists` disabled), \
+            so no words are known and none will be corrected. Load a word list at \
+            runtime instead, see `German::from_wordlist_path`."
+        );
+    });
+
+    // Nothing compiled in, so nothing is known.
+    false
+}
+
 // https://github.com/jaemk/cached/issues/135#issuecomment-1315911572
 #[cached(
-    type = "SizedCache<String, bool>",
+    type = "SizedCache<(Region, String), bool>",
     create = "{ SizedCache::with_size(1024) }",
-    convert = r#"{ String::from(word) }"#
+    convert = r#"{ (region, String::from(word)) }"#
 )]
-fn is_valid(word: &str, predicate: &impl Fn(&str) -> bool) -> bool {
+fn is_valid(word: &str, region: Region) -> bool {
+    is_valid_with(word, &|w: &str| contained_in_regional_word_list(w, region))
+}
+
+/// Whether `word` is valid, as judged by looking up it and its variants (casing,
+/// compound parts) using `predicate`.
+fn is_valid_with(word: &str, predicate: &impl Fn(&str) -> bool) -> bool {
     trace!("Trying candidate '{}'", word);
 
     let casing = WordCasing::try_from(word);
@@ -569,7 +1508,7 @@ fn is_valid(word: &str, predicate: &impl Fn(&str) -> bool) -> bool {
                 "Titlecased word, but isn't categorized correctly."
             );
 
-            is_valid(&tc, predicate)
+            is_valid_with(&tc, predicate)
         }
         Ok(WordCasing::Mixed) => {
             // For MiXeD casing, the word's first character governs its further
@@ -583,9 +1522,9 @@ fn is_valid(word: &str, predicate: &impl Fn(&str) -> bool) -> bool {
                         "Titlecased word, but isn't categorized correctly."
                     );
 
-                    is_valid(&tc, predicate)
+                    is_valid_with(&tc, predicate)
                 }
-                _ => is_valid(&word.to_lowercase(), predicate),
+                _ => is_valid_with(&word.to_lowercase(), predicate),
             }
         }
         Ok(WordCasing::Titlecase) => {
@@ -594,7 +1533,7 @@ fn is_valid(word: &str, predicate: &impl Fn(&str) -> bool) -> bool {
             predicate(word)
                 // Adjectives and verbs might be titlecased at the beginning of
                 // sentences etc. (e.g. "Gut gemacht!" -> we need "gut").
-                || is_valid(&word.to_lowercase(), predicate)
+                || is_valid_with(&word.to_lowercase(), predicate)
                 // None of these worked: we might have a compound word. In the ordinary
                 // case, these only occur as titlecase, as they're nouns (e.g.
                 // "Hausüberfall").
@@ -610,6 +1549,7 @@ mod tests {
     use rstest::rstest;
 
     #[test]
+    #[cfg(feature = "german-word-lists")]
     fn test_word_list_is_not_filtered() {
         let mut stream = SET.stream();
 
@@ -634,7 +1574,7 @@ mod tests {
 
     #[test]
     fn test_is_valid_on_empty_input() {
-        assert!(!is_valid("", &contained_in_global_word_list));
+        assert!(!is_valid("", Region::default()));
     }
 
     #[rstest]
@@ -678,6 +1618,11 @@ mod tests {
     #[case("Mauerdübelkübel", true)]
     #[case("Süßwasserschwimmbäder", true)]
     //
+    // Triple consonants at component boundaries
+    #[case("Schlussstrich", true)]
+    #[case("Flussschifffahrt", true)]
+    #[case("Schlußstrich", false)] // Old spelling
+    //
     // Foreign languages
     #[case("kindergarten", false)]
     #[case("Kindergarten", true)] // Capitalized in German
@@ -685,7 +1630,7 @@ mod tests {
     #[case("مرحبا", false)]
     #[case("你好", false)]
     fn test_is_valid(#[case] word: &str, #[case] expected: bool) {
-        assert_eq!(is_valid(word, &contained_in_global_word_list), expected);
+        assert_eq!(is_valid(word, Region::default()), expected);
     }
 
     #[rstest]
@@ -698,6 +1643,26 @@ mod tests {
     #[case("🤩Duebel", "🤩Dübel")]
     #[case("🤩Duebel🤐", "🤩Dübel🤐")]
     //
+    // Words spanning the entire input, touching both its start and end
+    #[case("Strasse", "Straße")]
+    #[case("Gruesse", "Grüße")]
+    #[case("STRASSE", "STRAẞE")]
+    #[case("Oel", "Öl")]
+    #[case("Suess", "Süß")] // Replacement right at the very end
+    #[case("Ueber", "Über")] // Replacement right at the very start
+    #[case("Strasse Gruesse", "Straße Grüße")]
+    //
+    // Characters surrounding corrected words are kept exactly
+    #[case("Strasse\t Gruesse", "Straße\t Grüße")]
+    #[case("Strasse  \t\t  Gruesse", "Straße  \t\t  Grüße")]
+    #[case(" \tStrasse\t ", " \tStraße\t ")]
+    #[case("Strasse\r\nGruesse\r\n", "Straße\r\nGrüße\r\n")]
+    #[case("Strasse\u{00A0}Gruesse", "Straße\u{00A0}Grüße")] // No-break space
+    #[case("Strasse\u{2009}Gruesse", "Straße\u{2009}Grüße")] // Thin space
+    #[case("Strasse\u{3000}Gruesse", "Straße\u{3000}Grüße")] // Ideographic space
+    #[case("Strasse\u{2028}Gruesse", "Straße\u{2028}Grüße")] // Line separator
+    #[case("(Strasse),\u{2003}«Gruesse»", "(Straße),\u{2003}«Grüße»")] // Em space
+    //
     // Legally alternative Umlaut/Eszett spelled words are not replaced
     #[case("Abenteuer sind toll!", "Abenteuer sind toll!")]
     #[case("Koeffizient", "Koeffizient")]
@@ -724,12 +1689,151 @@ mod tests {
         "Oel ist ein wichtiger Bestandteil von Oel.",
         "Öl ist ein wichtiger Bestandteil von Öl."
     )]
+    //
+    // Digits are not part of words, so letters attached to them are corrected
+    #[case("Strasse1", "Straße1")]
+    #[case("3Gruesse", "3Grüße")]
+    #[case("Haus42", "Haus42")] // Nothing to correct
+    #[case("42Strasse42", "42Straße42")]
+    #[case("Gruesse1Strasse", "Grüße1Straße")]
+    #[case("B2B-Geschaeft", "B2B-Geschäft")]
+    #[case("Strasse²", "Straße²")]
+    //
+    // Partially correct words: only the incorrect parts are fixed
+    #[case("Grüsse", "Grüße")]
+    #[case("Füsse", "Füße")]
+    #[case("Fuesse", "Füße")]
+    #[case("Grüße", "Grüße")]
+    #[case("Müsli", "Müsli")]
+    #[case("Viele Grüsse vom Müsli", "Viele Grüße vom Müsli")]
+    //
+    // Standalone special character spellings are no words, so are left alone
+    #[case("ue", "ue")]
+    #[case("ae", "ae")]
+    #[case("oe", "oe")]
+    #[case("ss", "ss")]
+    #[case("UE AE OE SS", "UE AE OE SS")]
+    #[case("Ue, Ae, Oe, Ss!", "Ue, Ae, Oe, Ss!")]
+    #[case("ue Tuer ae Baer oe Oel ss Strasse", "ue Tür ae Bär oe Öl ss Straße")]
+    //
+    // Quotation marks are not part of words
+    #[case("„Strasse“", "„Straße“")]
+    #[case("«Grüsse»", "«Grüße»")]
+    #[case("»Grüsse«", "»Grüße«")]
+    #[case("‟Strasse”", "‟Straße”")]
+    #[case("Er sagte: „Gruess Gott“.", "Er sagte: „Grüß Gott“.")]
+    //
+    // Loanwords, validly spelled with "ss"
+    #[case("Business", "Business")]
+    #[case("Stress", "Stress")]
+    #[case("Express", "Express")]
+    #[case("Adresse", "Adresse")]
+    #[case("Stresstest im Business", "Stresstest im Business")]
+    #[case("Per Express an die Adresse!", "Per Express an die Adresse!")]
+    //
+    // Compounds: corrections land on the right component only
+    #[case("Fussballtor", "Fußballtor")]
+    #[case("Grossstadt", "Großstadt")]
+    #[case("Massstab", "Maßstab")]
+    #[case("Schlussstrich", "Schlussstrich")] // Short vowel, never `ß`
+    #[case("Strassenschlussverkauf", "Straßenschlussverkauf")]
+    #[case("Kongresssaal", "Kongresssaal")]
+    #[case("Die Grossstadt am Schlussstrich", "Die Großstadt am Schlussstrich")]
+    //
+    // Triple consonants: a boundary `ss` after a short vowel stays, while other
+    // components are still corrected
+    #[case("Flussschifffahrt", "Flussschifffahrt")]
+    #[case("Kongressstadt", "Kongressstadt")]
+    #[case("Passstrasse", "Passstraße")]
+    #[case("Schlussstrasse", "Schlussstraße")]
     fn test_substitution(#[case] input: &str, #[case] expected: &str) {
         let action = German::default();
         let result = action.act(input);
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(&[ReplacementKind::Eszett], "Strasse Aepfel", "Straße Aepfel")]
+    #[case(&[ReplacementKind::Eszett], "Gruesse", "Gruesse")] // Valid only with both kinds
+    #[case(
+        &[ReplacementKind::Ae, ReplacementKind::Oe, ReplacementKind::Ue],
+        "Strasse Aepfel",
+        "Strasse Äpfel"
+    )]
+    #[case(&[ReplacementKind::Ue], "Oel ueber der Strasse", "Oel über der Strasse")]
+    #[case(&[], "Strasse Aepfel Oel", "Strasse Aepfel Oel")]
+    fn test_enabled_replacements(
+        #[case] kinds: &[ReplacementKind],
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) {
+        let mut action = German::default();
+        action.enabled_replacements(kinds.iter().copied().collect());
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("Haus", 0, 0, 0, 0, "Haus")]
+    #[case("Gruesse", 0, 0, 1, 1, "Grüße")]
+    #[case(
+        "Die Baeren oeffnen ueber Strassen die Tuer",
+        1,
+        1,
+        2,
+        1,
+        "Die Bären öffnen über Straßen die Tür"
+    )]
+    #[case("AEPFEL, Aepfel und Äpfel", 2, 0, 0, 0, "ÄPFEL, Äpfel und Äpfel")] // Present ones don't count
+    fn test_act_counting(
+        #[case] input: &str,
+        #[case] ae: usize,
+        #[case] oe: usize,
+        #[case] ue: usize,
+        #[case] eszett: usize,
+        #[case] expected: &str,
+    ) {
+        let action = German::default();
+        let (output, counts) = action.act_counting(input);
+
+        assert_eq!(output, expected);
+        assert_eq!(output, action.act(input));
+        assert_eq!(counts, ReplacementCounts { ae, oe, ue, eszett });
+    }
+
+    #[test]
+    fn test_output_is_independent_of_cache_state() {
+        // Enough distinct words to overflow the validity cache, so later words evict
+        // earlier ones. Real words are sprinkled in throughout, and get looked up both
+        // cold and (on the second run) partially warm.
+        let stems = ["Gruesse", "Strasse", "Fuesse", "Oelkanne", "Aerger"];
+        let mut words = Vec::new();
+
+        for i in 0..3_000_usize {
+            if i % 100 == 0 {
+                words.push(stems[(i / 100) % stems.len()].to_owned());
+            }
+
+            // Digits would end the word, so spell them as letters instead.
+            let suffix: String = format!("{i:04}")
+                .bytes()
+                .map(|d| char::from(d - b'0' + b'a'))
+                .collect();
+
+            words.push(format!("{}{suffix}", stems[i % stems.len()]));
+        }
+
+        let document = words.join(" ");
+        let action = German::default();
+
+        let first = action.act(&document);
+        let second = action.act(&document);
+
+        assert_eq!(first, second);
+        assert!(first.starts_with("Grüße "));
+        assert!(first.contains(" Straße "));
+    }
+
     #[rstest]
     // Single letter. Notice the mapping is irreversible.
     #[case("ue", "ü")]
@@ -756,4 +1860,357 @@ mod tests {
         let result = action.act(input);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case(1, "ae ss Suess", "ä ß Süß")]
+    #[case(2, "ae ss Suess", "ä ß Süß")]
+    #[case(3, "ae ss Suess", "ae ss Süß")]
+    #[case(5, "ae ss Suess", "ae ss Süß")]
+    #[case(6, "ae ss Suess", "ae ss Suess")]
+    fn test_min_word_len(#[case] len: usize, #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.naive().min_word_len(len);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(&["Strasse"], "Strasse", "Strasse")]
+    #[case(&["strasse"], "STRASSE und Strasse", "STRASSE und Strasse")]
+    #[case(&["Strasse"], "Strasse und Fuss", "Strasse und Fuß")]
+    #[case(&["Strasse"], "Strassen", "Straßen")] // Whole words only
+    #[case(&["Fuss", "Strasse"], "Fuss auf der Strasse", "Fuss auf der Strasse")]
+    #[case(&[], "Strasse", "Straße")]
+    fn test_never_correct(#[case] words: &[&str], #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.never_correct(words.iter().map(ToString::to_string).collect());
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_never_correct_when_naive() {
+        let mut action = German::default();
+        action
+            .naive()
+            .never_correct(HashSet::from(["Boeing".to_string()]));
+
+        assert_eq!(action.act("Boeing, Schroeder"), "Boeing, Schröder");
+    }
+
+    #[rstest]
+    #[case("Strasse", "Straße")]
+    #[case("Haus", "Haus")]
+    #[case("Aepfel", "Äpfel")]
+    #[case("STRASSE", "STRAẞE")]
+    #[case("Busse", "Buße")]
+    #[case("Straße", "Straße")]
+    //
+    // Not a single word: unchanged
+    #[case("", "")]
+    #[case("Strasse Strasse", "Strasse Strasse")]
+    #[case("Strasse!", "Strasse!")]
+    #[case(" Strasse", " Strasse")]
+    fn test_correct_word(#[case] word: &str, #[case] expected: &str) {
+        let result = German::default().correct_word(word);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_correct_word_borrows_if_unchanged() {
+        let action = German::default();
+
+        assert!(matches!(action.correct_word("Haus"), Cow::Borrowed("Haus")));
+        assert!(matches!(action.correct_word("Strasse"), Cow::Owned(_)));
+    }
+
+    #[rstest]
+    #[case(true, "Herr Strauss", "Herr Strauss")]
+    #[case(false, "Herr Strauss", "Herr Strauß")]
+    #[case(true, "ein Strauss", "ein Strauß")]
+    #[case(true, "ein strauss", "ein strauss")] // Nouns are capitalized; not a word
+    #[case(false, "ein strauss", "ein strauss")]
+    #[case(true, "Dr. Strauss und Frau Strauss", "Dr. Strauss und Frau Strauss")]
+    #[case(true, "Herr\nStrauss", "Herr\nStrauss")]
+    #[case(true, "Herr, Strauss", "Herr, Strauß")] // Not directly following
+    #[case(true, "Herr Mueller", "Herr Müller")] // Umlauts still replaced
+    fn test_name_heuristic(#[case] enabled: bool, #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.name_heuristic(enabled);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    // Soft hyphens
+    #[case("Stra\u{AD}sse", "Stra\u{AD}ße")]
+    #[case("Stras\u{AD}se", "Straß\u{AD}e")] // Inside of replacement: moves after it
+    #[case("Ue\u{AD}bel\u{AD}tae\u{AD}ter", "Ü\u{AD}bel\u{AD}tä\u{AD}ter")]
+    #[case("Mass\u{AD}krug", "Maß\u{AD}krug")]
+    //
+    // Zero-width characters
+    #[case("gross\u{200B}artig", "groß\u{200B}artig")]
+    #[case("Ue\u{200D}bel", "Ü\u{200D}bel")]
+    #[case("Duebel\u{FEFF}", "Dübel\u{FEFF}")] // Trailing, still kept in place
+    #[case("\u{200B}Duebel", "\u{200B}Dübel")] // Leading, not part of the word
+    #[case("Haus\u{AD}", "Haus\u{AD}")]
+    #[case("\u{AD}\u{200B}", "\u{AD}\u{200B}")]
+    //
+    // Words in sentences
+    #[case(
+        "Die Stra\u{AD}sse ist gross\u{200B}artig.",
+        "Die Stra\u{AD}ße ist groß\u{200B}artig."
+    )]
+    fn test_invisible_characters_inside_words(#[case] input: &str, #[case] expected: &str) {
+        let result = German::default().act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(false, "Breucke", "Breucke")]
+    #[case(true, "Breucke", "Brücke")]
+    #[case(true, "Die Breucke ist gross.", "Die Brücke ist groß.")]
+    #[case(true, "Haeuser", "Häuser")] // Plain replacement comes first
+    #[case(true, "Haus", "Haus")] // Valid words are not touched
+    #[case(true, "Bauer", "Bauer")]
+    #[case(true, "Hasue", "Hasue")] // No Umlaut resulting from any swap
+    #[case(true, "Xyzuea", "Xyzuea")] // Swaps yield no valid word
+    fn test_fuzzy(#[case] enabled: bool, #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.fuzzy(enabled);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    // Off by default
+    #[case(false, "Thür", "Thür")]
+    #[case(false, "Waſſer", "Waſſer")]
+    #[case(false, "Thuer", "Thuer")]
+    //
+    // Each mapping
+    #[case(true, "Thür", "Tür")]
+    #[case(true, "THÜR", "TÜR")]
+    #[case(true, "Theil", "Teil")]
+    #[case(true, "Waſſer", "Wasser")]
+    #[case(true, "seyn", "sein")]
+    #[case(true, "todt", "tot")]
+    //
+    // Combined with Umlauts and Eszett
+    #[case(true, "Thaeler", "Täler")]
+    #[case(true, "Fuſs", "Fuß")]
+    #[case(true, "Die Thür ist todt.", "Die Tür ist tot.")]
+    //
+    // Valid modern words are not touched
+    #[case(true, "Theater", "Theater")]
+    #[case(true, "Mathematik", "Mathematik")]
+    #[case(true, "Stadt", "Stadt")]
+    #[case(true, "Xyzth", "Xyzth")]
+    fn test_historical(#[case] enabled: bool, #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.historical(enabled);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("", &[])]
+    #[case("Haus", &[])]
+    #[case("Thür", &[(0..2, "T")])]
+    #[case("Waſſer", &[(2..4, "s"), (4..6, "s")])]
+    #[case("THEY", &[(0..2, "T"), (2..4, "EI")])]
+    #[case("Stadtheit", &[(3..5, "t")])] // Overlapping: the first one wins
+    fn test_historical_spellings(#[case] word: &str, #[case] expected: &[(Range<usize>, &str)]) {
+        assert_eq!(historical_spellings(word), expected);
+    }
+
+    #[rstest]
+    #[case('u', 'e', true)]
+    #[case('e', 'u', true)]
+    #[case('E', 'A', true)]
+    #[case('e', 'e', false)]
+    #[case('a', 'u', false)]
+    #[case('s', 's', false)]
+    fn test_is_transposable(#[case] a: char, #[case] b: char, #[case] expected: bool) {
+        assert_eq!(is_transposable(a, b), expected);
+    }
+
+    #[rstest]
+    #[case(Region::Germany, "Im Jaenner", "Im Jaenner")]
+    #[case(Region::Austria, "Im Jaenner", "Im Jänner")]
+    #[case(Region::Switzerland, "Im Jaenner", "Im Jaenner")]
+    #[case(Region::Austria, "Ein Kruegerl Bier", "Ein Krügerl Bier")]
+    #[case(Region::Germany, "Grueezi", "Grueezi")]
+    #[case(Region::Switzerland, "Grueezi", "Grüezi")]
+    #[case(Region::Germany, "Strasse", "Straße")]
+    #[case(Region::Austria, "Strasse", "Straße")]
+    #[case(Region::Switzerland, "Strasse", "Strasse")] // No Eszett in Switzerland
+    #[case(Region::Switzerland, "Ueberfluss", "Überfluss")] // Umlauts still replaced
+    fn test_region(#[case] region: Region, #[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.region(region);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("Jänner", Region::Germany, false)]
+    #[case("Jänner", Region::Austria, true)]
+    #[case("Haus", Region::Austria, true)] // Common word list applies everywhere
+    #[case("Grüezi", Region::Austria, false)]
+    #[case("Grüezi", Region::Switzerland, true)]
+    fn test_is_valid_in_region(#[case] word: &str, #[case] region: Region, #[case] expected: bool) {
+        assert_eq!(is_valid(word, region), expected);
+    }
+
+    #[rstest]
+    #[case("Fussball", "Fußball")] // Compound of listed words
+    #[case("FUSS", "FUẞ")]
+    #[case("Gruesse", "Gruesse")] // In the compiled-in word list, but not this one
+    #[case("Schloss", "Schloss")] // Replacement not listed
+    #[case("Oel", "Oel")]
+    fn test_from_wordlist_path(#[case] input: &str, #[case] expected: &str) {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        io::Write::write_all(&mut file, "Fuß\n  Ball \n\nSchloss\nFuß\n".as_bytes()).unwrap();
+
+        let action = German::from_wordlist_path(file.path()).unwrap();
+
+        assert_eq!(action.act(input), expected);
+    }
+
+    #[test]
+    fn test_from_wordlist_path_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+
+        assert!(German::from_wordlist_path(dir.path().join("missing.txt")).is_err());
+    }
+
+    #[rstest]
+    #[case("Strauss", "Herr", " ", true)]
+    #[case("Strauss", "herr", " ", true)]
+    #[case("Strauss", "Dr", ". ", true)]
+    #[case("Strauss", "Dr", ".", false)]
+    #[case("Strauss", "Herr", "", false)]
+    #[case("Strauss", "Herr", ", ", false)]
+    #[case("strauss", "Herr", " ", false)]
+    #[case("Strauss", "einen", " ", false)]
+    fn test_is_name_like(
+        #[case] word: &str,
+        #[case] previous_word: &str,
+        #[case] gap: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_name_like(word, previous_word, gap), expected);
+    }
+
+    #[rstest]
+    // Known words: the word list decides, the rule does not interfere
+    #[case("Fluss", "Fluss")] // Short vowel: ss
+    #[case("Fuss", "Fuß")] // Long vowel: ß
+    #[case("Kuss", "Kuss")]
+    #[case("heissen", "heißen")]
+    //
+    // Made-up words: the rule decides
+    #[case("Bleissel", "Bleißel")]
+    #[case("Blaussel", "Blaußel")]
+    #[case("Bliessel", "Bließel")]
+    #[case("Bloossel", "Blooßel")]
+    #[case("BLEISSEL", "BLEIẞEL")]
+    #[case("Blassel", "Blassel")]
+    #[case("Blussel", "Blussel")] // Long or short? Undecidable, so kept
+    fn test_eszett_rule(#[case] input: &str, #[case] expected: &str) {
+        let mut action = German::default();
+        action.eszett_rule(true);
+        let result = action.act(input);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = German::default().explain("Strasse").unwrap();
+
+        assert_eq!(
+            explanation,
+            Explanation {
+                word: "Strasse".into(),
+                candidates: vec![Candidate {
+                    word: "Straße".into(),
+                    valid: true,
+                }],
+                result: "Straße".into(),
+            }
+        );
+    }
+
+    #[rstest]
+    // Of a run of three `s`, only the first two form a candidate `ß`, so there is
+    // nothing to over-apply
+    #[case("Grossstadt", &["Großstadt"])]
+    #[case("Schlussstrich", &["Schlußstrich"])]
+    #[case("Kongresssaal", &["Kongreßsaal"])]
+    // Two candidate pairs, but replacing only the first already yields a valid word
+    #[case("Strassenschlussverkauf", &["Straßenschlussverkauf"])]
+    fn test_explain_compound_candidates(#[case] word: &str, #[case] expected: &[&str]) {
+        let explanation = German::default().explain(word).unwrap();
+        let candidates = explanation
+            .candidates
+            .iter()
+            .map(|c| c.word.as_str())
+            .collect_vec();
+
+        assert_eq!(candidates, expected);
+    }
+
+    #[test]
+    fn test_explain_prefer_original() {
+        let explanation = German::new(true, false).explain("Fuss").unwrap();
+
+        // The original comes first, and is invalid
+        assert_eq!(
+            explanation.candidates,
+            vec![
+                Candidate {
+                    word: "Fuss".into(),
+                    valid: false,
+                },
+                Candidate {
+                    word: "Fuß".into(),
+                    valid: true,
+                },
+            ]
+        );
+        assert_eq!(explanation.result, "Fuß");
+    }
+
+    #[rstest]
+    #[case("Hallo Strasse", "Hallo", "Hallo")] // Only the first word
+    #[case("  Strasse!", "Strasse", "Straße")]
+    #[case("Mauer", "Mauer", "Mauer")]
+    fn test_explain_word_and_result(#[case] input: &str, #[case] word: &str, #[case] result: &str) {
+        let explanation = German::default().explain(input).unwrap();
+
+        assert_eq!(explanation.word, word);
+        assert_eq!(explanation.result, result);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("!? 👋")]
+    fn test_explain_no_word(#[case] input: &str) {
+        assert_eq!(German::default().explain(input), None);
+    }
+
+    #[rstest]
+    #[case("", false)]
+    #[case("a", false)]
+    #[case("Flu", false)]
+    #[case("Fu", false)] // Long, but undetectable
+    #[case("hei", true)]
+    #[case("HEI", true)]
+    #[case("drau", true)]
+    #[case("Schlie", true)]
+    #[case("Maa", true)]
+    fn test_ends_in_long_vowel(#[case] preceding: &str, #[case] expected: bool) {
+        assert_eq!(ends_in_long_vowel(preceding), expected);
+    }
 }
@@ -68,7 +68,7 @@ impl StateMachine {
             self.word.clear();
 
             trace!("Cleared current word, machine now is: {self:?}.");
-        };
+        }
     }
 
     pub fn transition(&mut self, input: MachineInput) -> Transition {
@@ -143,8 +143,63 @@ impl StateMachine {
                 input,
                 self.transition
             );
-        };
+        }
 
         trace!("After transition, machine is: {self:?}.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // German
+    #[case('„')]
+    #[case('“')]
+    #[case('‟')]
+    #[case('‚')]
+    #[case('‘')]
+    // Guillemets
+    #[case('«')]
+    #[case('»')]
+    #[case('‹')]
+    #[case('›')]
+    // ASCII
+    #[case('"')]
+    #[case('\'')]
+    fn test_quotation_marks_are_external(#[case] quote: char) {
+        let mut machine = StateMachine::new();
+
+        assert!(matches!(machine.transition(quote), Transition::External));
+        assert!(matches!(machine.transition('a'), Transition::Entered));
+        assert!(matches!(machine.transition(quote), Transition::Exited));
+        assert_eq!(machine.current_word().content(), "a");
+    }
+
+    #[rstest]
+    #[case('0')]
+    #[case('9')]
+    #[case('²')]
+    #[case('٣')] // Arabic-Indic digit three
+    fn test_digits_are_external(#[case] digit: char) {
+        let mut machine = StateMachine::new();
+
+        assert!(matches!(machine.transition(digit), Transition::External));
+        assert!(matches!(machine.transition('a'), Transition::Entered));
+        assert!(matches!(machine.transition(digit), Transition::Exited));
+        assert_eq!(machine.current_word().content(), "a");
+    }
+
+    #[test]
+    fn test_word_at_very_start_of_input() {
+        let mut machine = StateMachine::new();
+
+        // No preceding external character: the very first transition enters.
+        assert!(matches!(machine.transition('S'), Transition::Entered));
+        assert!(matches!(machine.transition('s'), Transition::Internal));
+        assert!(matches!(machine.transition('\0'), Transition::Exited));
+        assert_eq!(machine.current_word().content(), "Ss");
+    }
+}
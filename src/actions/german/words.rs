@@ -73,20 +73,22 @@ pub(super) enum Umlaut {
     Ue(LetterCasing),
 }
 
+impl From<Umlaut> for char {
+    fn from(umlaut: Umlaut) -> Self {
+        match umlaut {
+            Umlaut::Ae(LetterCasing::Lower) => 'ä',
+            Umlaut::Ae(LetterCasing::Upper) => 'Ä',
+            Umlaut::Oe(LetterCasing::Lower) => 'ö',
+            Umlaut::Oe(LetterCasing::Upper) => 'Ö',
+            Umlaut::Ue(LetterCasing::Lower) => 'ü',
+            Umlaut::Ue(LetterCasing::Upper) => 'Ü',
+        }
+    }
+}
+
 impl Display for Umlaut {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Umlaut::Ae(LetterCasing::Lower) => 'ä',
-                Umlaut::Ae(LetterCasing::Upper) => 'Ä',
-                Umlaut::Oe(LetterCasing::Lower) => 'ö',
-                Umlaut::Oe(LetterCasing::Upper) => 'Ö',
-                Umlaut::Ue(LetterCasing::Lower) => 'ü',
-                Umlaut::Ue(LetterCasing::Upper) => 'Ü',
-            }
-        )
+        write!(f, "{}", char::from(*self))
     }
 }
 
@@ -178,38 +180,45 @@ impl Replacement {
 
 pub(super) trait Replace {
     fn apply_replacement(&mut self, replacement: &Replacement);
-    fn apply_replacements<T>(&mut self, replacements: T)
+    fn apply_replacements<'a, I>(&mut self, replacements: I)
     where
-        T: IntoIterator<Item = Replacement>,
-        T::IntoIter: DoubleEndedIterator<Item = Replacement>;
+        I: IntoIterator<Item = &'a Replacement>,
+        I::IntoIter: DoubleEndedIterator<Item = &'a Replacement> + Clone;
 }
 
 impl Replace for String {
     fn apply_replacement(&mut self, replacement: &Replacement) {
-        self.replace_range(
-            replacement.start()..replacement.end(),
-            &replacement.content().to_string(),
-        );
+        // Avoid allocating an intermediary `String` via `to_string`.
+        let mut buf = [0; 4];
+        let content: &str = match replacement.content() {
+            SpecialCharacter::Umlaut(umlaut) => char::from(*umlaut).encode_utf8(&mut buf),
+            SpecialCharacter::Eszett(LetterCasing::Lower) => 'ß'.encode_utf8(&mut buf),
+            SpecialCharacter::Eszett(LetterCasing::Upper) => 'ẞ'.encode_utf8(&mut buf),
+        };
+
+        self.replace_range(replacement.start()..replacement.end(), content);
     }
 
-    fn apply_replacements<I>(&mut self, replacements: I)
+    fn apply_replacements<'a, I>(&mut self, replacements: I)
     where
-        I: IntoIterator<Item = Replacement>,
-        I::IntoIter: DoubleEndedIterator<Item = Replacement>,
+        I: IntoIterator<Item = &'a Replacement>,
+        I::IntoIter: DoubleEndedIterator<Item = &'a Replacement> + Clone,
     {
-        let replacements = replacements.into_iter().collect_vec();
+        let replacements = replacements.into_iter();
 
         // Assert sorting, such that reversing actually does the right thing.
-        if cfg!(debug_assertions) {
-            let mut cloned = replacements.iter().cloned().collect_vec();
-            cloned.sort_by_key(crate::actions::german::words::Replacement::start);
-            assert_eq!(cloned, replacements);
-        }
+        debug_assert!(
+            replacements
+                .clone()
+                .tuple_windows()
+                .all(|(a, b)| a.start() <= b.start()),
+            "Replacements not sorted"
+        );
 
         // We are replacing starting from behind. Otherwise, earlier indices invalidate
         // later ones.
-        for replacement in replacements.into_iter().rev() {
-            self.apply_replacement(&replacement);
+        for replacement in replacements.rev() {
+            self.apply_replacement(replacement);
         }
     }
 }
@@ -0,0 +1,155 @@
+use super::Action;
+use log::info;
+
+/// The unit of indentation, i.e. what a single level of indentation consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentUnit {
+    /// The given number of spaces.
+    Spaces(usize),
+    /// A single tab.
+    Tab,
+}
+
+impl Default for IndentUnit {
+    /// Four spaces.
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
+}
+
+impl IndentUnit {
+    /// The character this unit consists of, and how many of them make up one level.
+    fn parts(self) -> (char, usize) {
+        match self {
+            Self::Spaces(width) => (' ', width),
+            Self::Tab => ('\t', 1),
+        }
+    }
+}
+
+/// Shifts lines by some levels of indentation, inwards or outwards.
+///
+/// A positive shift adds indentation at the start of each line, a negative one removes
+/// it. When removing, only leading indentation of the configured [`IndentUnit`] is
+/// removed, and at most as much as is there. Blank lines are left alone, as are line
+/// endings.
+///
+/// The start of a scope is considered the start of a line. For this to be useful,
+/// scopes should therefore span entire lines.
+///
+/// ## Example: indenting a block by one level
+///
+/// ```rust
+/// use srgn::actions::{Action, Indent, IndentUnit};
+///
+/// let action = Indent::new(IndentUnit::Spaces(2), 1);
+/// let result = action.act("if x:\n  y()\n\nz()\n");
+///
+/// assert_eq!(result, "  if x:\n    y()\n\n  z()\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Indent {
+    unit: IndentUnit,
+    levels: isize,
+}
+
+impl Indent {
+    /// Create a new [`Indent`], shifting by `levels` of the given `unit`. Negative
+    /// `levels` remove indentation.
+    #[must_use]
+    pub fn new(unit: IndentUnit, levels: isize) -> Self {
+        Self { unit, levels }
+    }
+}
+
+impl Default for Indent {
+    /// Indent by one level of the default [`IndentUnit`].
+    fn default() -> Self {
+        Self::new(IndentUnit::default(), 1)
+    }
+}
+
+impl Action for Indent {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Shifting '{}' by {} levels of {:?}",
+            input.escape_debug(),
+            self.levels,
+            self.unit
+        );
+
+        let (c, width) = self.unit.parts();
+        let n = self.levels.unsigned_abs() * width;
+        let indentation = c.to_string().repeat(n);
+
+        let mut res = String::with_capacity(input.len());
+
+        for line in input.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                res.push_str(line);
+                continue;
+            }
+
+            if self.levels >= 0 {
+                res.push_str(&indentation);
+                res.push_str(line);
+            } else {
+                let n_leading = line.chars().take_while(|&l| l == c).take(n).count();
+                // Only ever ASCII, so character count is byte count.
+                res.push_str(&line[n_leading..]);
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", Indent::default(), "")]
+    #[case("a", Indent::new(IndentUnit::Spaces(4), 0), "a")]
+    #[case("a", Indent::default(), "    a")]
+    #[case("    a", Indent::new(IndentUnit::Spaces(4), -1), "a")]
+    //
+    // Blank lines are left alone
+    #[case("a\n\nb\n", Indent::new(IndentUnit::Spaces(2), 1), "  a\n\n  b\n")]
+    #[case("a\n  \nb", Indent::new(IndentUnit::Spaces(2), 1), "  a\n  \n  b")]
+    #[case("  a\n  \n  b", Indent::new(IndentUnit::Spaces(2), -1), "a\n  \nb")]
+    #[case(
+        "a\r\n\r\nb\r\n",
+        Indent::new(IndentUnit::Tab, 1),
+        "\ta\r\n\r\n\tb\r\n"
+    )]
+    //
+    // Multiple levels
+    #[case("a\nb", Indent::new(IndentUnit::Spaces(2), 2), "    a\n    b")]
+    #[case("\t\t\ta", Indent::new(IndentUnit::Tab, -2), "\ta")]
+    //
+    // Removes no more than there is, and only of the given unit
+    #[case("  a\n      b", Indent::new(IndentUnit::Spaces(4), -1), "a\n  b")]
+    #[case("\ta", Indent::new(IndentUnit::Spaces(4), -1), "\ta")]
+    #[case("    a", Indent::new(IndentUnit::Tab, -1), "    a")]
+    //
+    // Realistic example: shifting a block in and back out
+    #[case(
+        "def f():\n    return 1\n\nf()\n",
+        Indent::new(IndentUnit::Spaces(4), 1),
+        "    def f():\n        return 1\n\n    f()\n"
+    )]
+    #[case(
+        "    def f():\n        return 1\n\n    f()\n",
+        Indent::new(IndentUnit::Spaces(4), -1),
+        "def f():\n    return 1\n\nf()\n"
+    )]
+    fn test_indent(#[case] input: &str, #[case] action: Indent, #[case] expected: &str) {
+        let result = action.act(input);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -1,24 +1,50 @@
+mod collapse;
 mod deletion;
+mod escape;
+mod exec;
 #[cfg(feature = "german")]
 mod german;
+mod indent;
 mod lower;
 mod normalization;
+mod number;
+mod redact;
 mod replace;
+mod reverse;
+mod sort;
+mod surround;
 #[cfg(feature = "symbols")]
 mod symbols;
+mod tabs;
 mod titlecase;
+mod transform;
+mod trim;
 mod upper;
+mod wrap;
 
+pub use collapse::CollapseBlankLines;
 pub use deletion::Deletion;
+pub use escape::{Escape, EscapeMode, Unescape};
+pub use exec::{Exec, ExecError};
 #[cfg(feature = "german")]
-pub use german::German;
+pub use german::{Candidate, Explanation, German, Region, ReplacementCounts, ReplacementKind};
+pub use indent::{Indent, IndentUnit};
 pub use lower::Lower;
 pub use normalization::Normalization;
+pub use number::NumberLines;
+pub use redact::{Redact, RedactMode};
 pub use replace::{Replacement, ReplacementCreationError};
+pub use reverse::ReverseLines;
+pub use sort::SortLines;
+pub use surround::Surround;
 #[cfg(feature = "symbols")]
 pub use symbols::{inversion::SymbolsInversion, Symbols};
+pub use tabs::{Expand, Unexpand};
 pub use titlecase::Titlecase;
+pub use transform::{Transform, TransformError};
+pub use trim::TrimTrailing;
 pub use upper::Upper;
+pub use wrap::Wrap;
 
 /// An action in the processing pipeline.
 ///
@@ -31,8 +57,25 @@ pub trait Action: Send + Sync {
     /// This is infallible: it cannot fail in the sense of [`Result`]. It can only
     /// return incorrect results, which would be bugs (please report).
     fn act(&self, input: &str) -> String;
+
+    /// Apply this action to the given input, reporting failures.
+    ///
+    /// Actions relying on the outside world, such as [`Exec`], can fail. Their
+    /// [`Action::act`] then leaves the input unchanged, logging an error, while this
+    /// method reports the failure, allowing to abort processing instead. For all other
+    /// actions, this is [`Action::act`].
+    ///
+    /// # Errors
+    ///
+    /// If the action failed to process `input`.
+    fn act_checked(&self, input: &str) -> Result<String, ActionError> {
+        Ok(self.act(input))
+    }
 }
 
+/// An error reported by [`Action::act_checked`].
+pub type ActionError = Box<dyn std::error::Error + Send + Sync>;
+
 /// Any function that can be used as an [`Action`].
 impl<T> Action for T
 where
@@ -49,4 +92,8 @@ impl Action for Box<dyn Action> {
     fn act(&self, input: &str) -> String {
         self.as_ref().act(input)
     }
+
+    fn act_checked(&self, input: &str) -> Result<String, ActionError> {
+        self.as_ref().act_checked(input)
+    }
 }
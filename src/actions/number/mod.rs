@@ -0,0 +1,155 @@
+use super::Action;
+use log::info;
+
+/// The placeholder in a [`NumberLines`] format which is replaced by the line number.
+const PLACEHOLDER: &str = "{n}";
+
+/// The default format of line number prefixes.
+const DEFAULT_FORMAT: &str = "{n} ";
+
+/// Prefixes each line with its number, much like `cat -n`.
+///
+/// Lines are numbered starting at a configurable number, counting up by a configurable
+/// step. The prefix is built from a format, in which every `{n}` is replaced by the
+/// line number. A format without `{n}` is a plain prefix.
+///
+/// Every line is numbered, blank ones included. Numbering starts afresh for each call
+/// (so for each part of scope): to number lines across an entire input, scope it as a
+/// whole (e.g. with `(?s).*`).
+///
+/// ## Example: numbering lines
+///
+/// ```rust
+/// use srgn::actions::{Action, NumberLines};
+///
+/// let action = NumberLines::default();
+/// let result = action.act("a\nb\n");
+///
+/// assert_eq!(result, "1 a\n2 b\n");
+/// ```
+///
+/// ## Example: custom start, step and format
+///
+/// ```rust
+/// use srgn::actions::{Action, NumberLines};
+///
+/// let mut action = NumberLines::default();
+/// action.start(10).step(10).format("{n}: ");
+/// let result = action.act("a\nb\n");
+///
+/// assert_eq!(result, "10: a\n20: b\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberLines {
+    start: usize,
+    step: usize,
+    format: String,
+}
+
+impl NumberLines {
+    /// Start numbering at `start`.
+    pub fn start(&mut self, start: usize) -> &mut Self {
+        self.start = start;
+        self
+    }
+
+    /// Count up by `step` for each line. A `step` of zero numbers all lines the same.
+    pub fn step(&mut self, step: usize) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// Use `format` for prefixes, with every `{n}` replaced by the line number.
+    pub fn format(&mut self, format: impl Into<String>) -> &mut Self {
+        self.format = format.into();
+        self
+    }
+}
+
+impl Default for NumberLines {
+    /// Numbers lines starting at 1, counting up by 1, as `"{n} "`.
+    fn default() -> Self {
+        Self {
+            start: 1,
+            step: 1,
+            format: DEFAULT_FORMAT.to_owned(),
+        }
+    }
+}
+
+impl Action for NumberLines {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Numbering lines of '{}' from {} by {} as '{}'",
+            input.escape_debug(),
+            self.start,
+            self.step,
+            self.format
+        );
+
+        let mut res = String::with_capacity(input.len());
+        let mut n = self.start;
+
+        for line in input.split_inclusive('\n') {
+            res.push_str(&self.format.replace(PLACEHOLDER, &n.to_string()));
+            res.push_str(line);
+
+            n = n.saturating_add(self.step);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", 1, 1, "{n} ", "")]
+    #[case("a", 1, 1, "{n} ", "1 a")]
+    #[case("a\n", 1, 1, "{n} ", "1 a\n")]
+    //
+    // Three lines, at various starts
+    #[case("a\nb\nc\n", 1, 1, "{n} ", "1 a\n2 b\n3 c\n")]
+    #[case("a\nb\nc\n", 100, 1, "{n} ", "100 a\n101 b\n102 c\n")]
+    #[case("a\nb\nc", 0, 1, "{n} ", "0 a\n1 b\n2 c")]
+    //
+    // Steps
+    #[case("a\nb\nc\n", 10, 10, "{n} ", "10 a\n20 b\n30 c\n")]
+    #[case("a\nb\n", 5, 0, "{n} ", "5 a\n5 b\n")]
+    //
+    // Formats
+    #[case("a\nb\n", 1, 1, "{n}\t", "1\ta\n2\tb\n")]
+    #[case("a\nb\n", 1, 1, "[{n}/{n}] ", "[1/1] a\n[2/2] b\n")]
+    #[case("a\nb\n", 1, 1, "> ", "> a\n> b\n")]
+    #[case("a\nb\n", 1, 1, "", "a\nb\n")]
+    //
+    // Blank lines are numbered; line endings are kept
+    #[case("a\n\nb\n", 1, 1, "{n} ", "1 a\n2 \n3 b\n")]
+    #[case("a\r\nb\r\n", 1, 1, "{n} ", "1 a\r\n2 b\r\n")]
+    fn test_number_lines(
+        #[case] input: &str,
+        #[case] start: usize,
+        #[case] step: usize,
+        #[case] format: &str,
+        #[case] expected: &str,
+    ) {
+        let mut action = NumberLines::default();
+        action.start(start).step(step).format(format);
+
+        assert_eq!(action.act(input), expected);
+    }
+
+    #[test]
+    fn test_number_lines_saturates() {
+        let mut action = NumberLines::default();
+        action.start(usize::MAX);
+
+        let max = usize::MAX;
+        assert_eq!(action.act("a\nb\n"), format!("{max} a\n{max} b\n"));
+    }
+}
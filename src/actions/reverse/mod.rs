@@ -0,0 +1,75 @@
+use super::Action;
+use log::info;
+
+/// Reverses the order of lines.
+///
+/// The input is split into lines, which are joined back together in reverse order.
+/// Line endings are preserved: DOS-style (`\r\n`) input yields DOS-style output, and
+/// a trailing line ending is kept if (and only if) the input had one.
+///
+/// As with [`SortLines`](super::SortLines), reversing happens *within* each scope. For
+/// it to be useful, scopes need to span multiple lines.
+///
+/// ## Example: reversing a block of lines
+///
+/// ```rust
+/// use srgn::actions::{Action, ReverseLines};
+///
+/// let action = ReverseLines::default();
+/// let result = action.act("first\nsecond\nthird\n");
+///
+/// assert_eq!(result, "third\nsecond\nfirst\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReverseLines {}
+
+impl Action for ReverseLines {
+    fn act(&self, input: &str) -> String {
+        info!("Reversing lines of '{}'", input.escape_debug());
+
+        let line_ending = if input.contains("\r\n") { "\r\n" } else { "\n" };
+        let has_trailing_line_ending = input.ends_with('\n');
+
+        let mut lines: Vec<&str> = input.lines().collect();
+        lines.reverse();
+
+        let mut res = lines.join(line_ending);
+        if has_trailing_line_ending {
+            res.push_str(line_ending);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", "")]
+    #[case("a", "a")]
+    #[case("\n", "\n")]
+    //
+    // Trailing line ending presence is preserved
+    #[case("a\nb\nc", "c\nb\na")]
+    #[case("a\nb\nc\n", "c\nb\na\n")]
+    #[case("a\r\nb\r\nc\r\n", "c\r\nb\r\na\r\n")]
+    //
+    // Blank lines are lines too
+    #[case("a\n\nb\n", "b\n\na\n")]
+    //
+    // Realistic example
+    #[case(
+        "- Fix crash on empty input\n- Add --reverse-lines\n- Bump MSRV\n",
+        "- Bump MSRV\n- Add --reverse-lines\n- Fix crash on empty input\n"
+    )]
+    fn test_reverse_lines(#[case] input: &str, #[case] expected: &str) {
+        let result = ReverseLines::default().act(input);
+
+        assert_eq!(result, expected);
+    }
+}
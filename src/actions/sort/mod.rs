@@ -0,0 +1,157 @@
+use super::Action;
+use log::info;
+
+/// Sorts lines.
+///
+/// The input is split into lines, which are sorted and joined back together. Line
+/// endings are preserved: DOS-style (`\r\n`) input yields DOS-style output, and a
+/// trailing line ending is kept if (and only if) the input had one.
+///
+/// Note that the sorting happens *within* each scope. For it to be useful, scopes
+/// need to span multiple lines.
+///
+/// ## Example: sorting a block of lines
+///
+/// ```rust
+/// use srgn::actions::{Action, SortLines};
+///
+/// let action = SortLines::default();
+/// let result = action.act("banana\napple\ncherry\n");
+///
+/// assert_eq!(result, "apple\nbanana\ncherry\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SortLines {
+    case_insensitive: bool,
+    reverse: bool,
+    unique: bool,
+}
+
+impl SortLines {
+    /// Create a new [`SortLines`].
+    ///
+    /// # Arguments
+    ///
+    /// * `case_insensitive`: If `true`, compare lines disregarding their case.
+    /// * `reverse`: If `true`, sort in descending order.
+    /// * `unique`: If `true`, drop all but the first of otherwise equal lines (equality
+    ///   respecting `case_insensitive`).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use srgn::actions::{Action, SortLines};
+    ///
+    /// let action = SortLines::new(true, true, true);
+    /// let result = action.act("b\nA\na\nc\nB");
+    ///
+    /// assert_eq!(result, "c\nb\nA");
+    /// ```
+    #[must_use]
+    pub fn new(case_insensitive: bool, reverse: bool, unique: bool) -> Self {
+        Self {
+            case_insensitive,
+            reverse,
+            unique,
+        }
+    }
+
+    /// Compare lines disregarding their case.
+    pub fn case_insensitive(&mut self) -> &mut Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Sort in descending order.
+    pub fn reverse(&mut self) -> &mut Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Drop duplicate lines.
+    pub fn unique(&mut self) -> &mut Self {
+        self.unique = true;
+        self
+    }
+}
+
+impl Action for SortLines {
+    fn act(&self, input: &str) -> String {
+        info!("Sorting lines of '{}'", input.escape_debug());
+
+        let line_ending = if input.contains("\r\n") { "\r\n" } else { "\n" };
+        let has_trailing_line_ending = input.ends_with('\n');
+
+        let key = |line: &str| {
+            if self.case_insensitive {
+                line.to_lowercase()
+            } else {
+                line.to_owned()
+            }
+        };
+
+        let mut lines: Vec<&str> = input.lines().collect();
+
+        // Stable, so with `unique`, the first of equal lines is kept.
+        lines.sort_by_cached_key(|line| key(line));
+
+        if self.unique {
+            lines.dedup_by(|a, b| key(a) == key(b));
+        }
+
+        if self.reverse {
+            lines.reverse();
+        }
+
+        let mut res = lines.join(line_ending);
+        if has_trailing_line_ending {
+            res.push_str(line_ending);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", SortLines::default(), "")]
+    #[case("a", SortLines::default(), "a")]
+    #[case("\n", SortLines::default(), "\n")]
+    //
+    // Ascending
+    #[case("b\na\nc", SortLines::default(), "a\nb\nc")]
+    #[case("b\na\nc\n", SortLines::default(), "a\nb\nc\n")]
+    #[case("b\r\na\r\nc\r\n", SortLines::default(), "a\r\nb\r\nc\r\n")]
+    #[case("B\na\nb\nA", SortLines::default(), "A\nB\na\nb")]
+    #[case("b\n\na", SortLines::default(), "\na\nb")]
+    //
+    // Case insensitivity
+    #[case("B\na\nb\nA", SortLines::new(true, false, false), "a\nA\nB\nb")]
+    //
+    // Reverse
+    #[case("b\na\nc\n", SortLines::new(false, true, false), "c\nb\na\n")]
+    //
+    // Unique
+    #[case("b\na\nb\na\n", SortLines::new(false, false, true), "a\nb\n")]
+    #[case("B\na\nb\nA", SortLines::new(false, false, true), "A\nB\na\nb")]
+    #[case("B\na\nb\nA", SortLines::new(true, false, true), "a\nB")]
+    #[case("b\na\nb\na\n", SortLines::new(false, true, true), "b\na\n")]
+    //
+    // Realistic example
+    #[case(
+        "import sys\nimport os\nimport abc\nimport os\n",
+        SortLines::new(false, false, true),
+        "import abc\nimport os\nimport sys\n"
+    )]
+    fn test_sort_lines(#[case] input: &str, #[case] action: SortLines, #[case] expected: &str) {
+        let result = action.act(input);
+
+        assert_eq!(result, expected);
+    }
+}
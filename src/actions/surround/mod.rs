@@ -0,0 +1,83 @@
+use super::Action;
+use log::info;
+
+/// Surrounds input with a fixed prefix and suffix.
+///
+/// The prefix and suffix are added as-is and are not themselves subject to scoping.
+/// Note however that any [`Action`] applied *afterwards* will see them as part of the
+/// scope.
+///
+/// ## Example: marking all numbers
+///
+/// ```rust
+/// use srgn::RegexPattern;
+/// use srgn::actions::Surround;
+/// use srgn::scoping::{view::ScopedViewBuilder, regex::Regex};
+///
+/// let scoper = Regex::new(RegexPattern::new(r"\d+").unwrap());
+/// let mut builder = ScopedViewBuilder::new("Call 555 or 911");
+/// builder.explode(&scoper);
+/// let mut view = builder.build();
+/// view.map(&Surround::new("<".to_string(), ">".to_string()));
+///
+/// assert_eq!(view.to_string(), "Call <555> or <911>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Surround {
+    prefix: String,
+    suffix: String,
+}
+
+impl Surround {
+    /// Create a new [`Surround`] with the given `prefix` and `suffix`.
+    #[must_use]
+    pub fn new(prefix: String, suffix: String) -> Self {
+        Self { prefix, suffix }
+    }
+}
+
+impl Action for Surround {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Surrounding '{}' with '{}' and '{}'",
+            input.escape_debug(),
+            self.prefix.escape_debug(),
+            self.suffix.escape_debug()
+        );
+
+        let mut res = String::with_capacity(self.prefix.len() + input.len() + self.suffix.len());
+        res.push_str(&self.prefix);
+        res.push_str(input);
+        res.push_str(&self.suffix);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("a", "", "", "a")]
+    #[case("a", "<", "", "<a")]
+    #[case("a", "", ">", "a>")]
+    #[case("a", "<", ">", "<a>")]
+    #[case("", "<", ">", "<>")]
+    //
+    #[case("'Hello'", "`", "`", "`'Hello'`")]
+    #[case("// A comment", "/* ", " */", "/* // A comment */")]
+    #[case("Grüß 你好 👋", "«", "»", "«Grüß 你好 👋»")]
+    fn test_surround(
+        #[case] input: &str,
+        #[case] prefix: &str,
+        #[case] suffix: &str,
+        #[case] expected: &str,
+    ) {
+        let action = Surround::new(prefix.to_string(), suffix.to_string());
+        let result = action.act(input);
+
+        assert_eq!(result, expected);
+    }
+}
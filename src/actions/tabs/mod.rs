@@ -0,0 +1,209 @@
+use super::Action;
+use log::info;
+
+/// The default width of a tab, in columns.
+const DEFAULT_TAB_WIDTH: usize = 4;
+
+/// Converts leading tabs to spaces.
+///
+/// Only leading indentation of each line is affected. A tab advances to the next tab
+/// stop, so tabs following spaces might expand to fewer spaces than the tab width.
+/// Tabs elsewhere, e.g. inside of strings, are left alone (unless scoped there, making
+/// them leading). Blank lines are left alone, as are line endings.
+///
+/// ## Example: expanding to four spaces
+///
+/// ```rust
+/// use srgn::actions::{Action, Expand};
+///
+/// let action = Expand::new(4);
+/// let result = action.act("if x:\n\ty('\t')\n\t\tz()\n");
+///
+/// assert_eq!(result, "if x:\n    y('\t')\n        z()\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Expand {
+    width: usize,
+}
+
+impl Expand {
+    /// Create a new [`Expand`], with tab stops every `width` columns. A `width` of zero
+    /// is treated as one.
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Self {
+            width: width.max(1),
+        }
+    }
+}
+
+impl Default for Expand {
+    /// Tab stops every four columns.
+    fn default() -> Self {
+        Self::new(DEFAULT_TAB_WIDTH)
+    }
+}
+
+impl Action for Expand {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Expanding tabs of '{}' to width {}",
+            input.escape_debug(),
+            self.width
+        );
+
+        map_indentation(input, self.width, |columns| " ".repeat(columns))
+    }
+}
+
+/// Converts leading runs of spaces to tabs.
+///
+/// The inverse of [`Expand`]: leading indentation of each line is measured in columns
+/// (tabs advancing to the next tab stop), and rewritten as tabs, followed by any spaces
+/// which do not fill up an entire tab. Only leading indentation is affected. Blank
+/// lines are left alone, as are line endings.
+///
+/// ## Example: unexpanding from four spaces
+///
+/// ```rust
+/// use srgn::actions::{Action, Unexpand};
+///
+/// let action = Unexpand::new(4);
+/// let result = action.act("if x:\n    y('    ')\n      z()\n");
+///
+/// assert_eq!(result, "if x:\n\ty('    ')\n\t  z()\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unexpand {
+    width: usize,
+}
+
+impl Unexpand {
+    /// Create a new [`Unexpand`], with tab stops every `width` columns. A `width` of
+    /// zero is treated as one.
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Self {
+            width: width.max(1),
+        }
+    }
+}
+
+impl Default for Unexpand {
+    /// Tab stops every four columns.
+    fn default() -> Self {
+        Self::new(DEFAULT_TAB_WIDTH)
+    }
+}
+
+impl Action for Unexpand {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Unexpanding spaces of '{}' at width {}",
+            input.escape_debug(),
+            self.width
+        );
+
+        map_indentation(input, self.width, |columns| {
+            let mut indentation = "\t".repeat(columns / self.width);
+            indentation.push_str(&" ".repeat(columns % self.width));
+            indentation
+        })
+    }
+}
+
+/// Replaces the leading indentation of each non-blank line by what `f` returns for its
+/// width in columns, with tab stops every `width` columns.
+fn map_indentation(input: &str, width: usize, f: impl Fn(usize) -> String) -> String {
+    let mut res = String::with_capacity(input.len());
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            res.push_str(line);
+            continue;
+        }
+
+        let rest = line.trim_start_matches([' ', '\t']);
+        let indentation = &line[..line.len() - rest.len()];
+
+        let columns = indentation.chars().fold(0, |column, c| match c {
+            '\t' => column + width - column % width,
+            _ => column + 1,
+        });
+
+        res.push_str(&f(columns));
+        res.push_str(rest);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", 4, "")]
+    #[case("a", 4, "a")]
+    #[case("\ta", 4, "    a")]
+    #[case("\t\ta", 4, "        a")]
+    #[case("\ta", 2, "  a")]
+    //
+    // Tab stops
+    #[case("  \ta", 4, "    a")]
+    #[case(" \t\ta", 4, "        a")]
+    #[case("\t  a", 4, "      a")]
+    //
+    // Only leading indentation
+    #[case("\ta\tb\n", 4, "    a\tb\n")]
+    #[case("\tx = '\t'\r\n", 4, "    x = '\t'\r\n")]
+    //
+    // Blank lines are left alone
+    #[case("\ta\n\t\n\tb", 4, "    a\n\t\n    b")]
+    fn test_expand(#[case] input: &str, #[case] width: usize, #[case] expected: &str) {
+        let result = Expand::new(width).act(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    // Base cases
+    #[case("", 4, "")]
+    #[case("a", 4, "a")]
+    #[case("    a", 4, "\ta")]
+    #[case("        a", 4, "\t\ta")]
+    #[case("  a", 2, "\ta")]
+    //
+    // Leftover spaces
+    #[case("  a", 4, "  a")]
+    #[case("      a", 4, "\t  a")]
+    //
+    // Mixed input
+    #[case("  \t  a", 4, "\t  a")]
+    #[case("\t    a", 4, "\t\ta")]
+    //
+    // Only leading indentation
+    #[case("    a    b\n", 4, "\ta    b\n")]
+    #[case("    x = '    '\r\n", 4, "\tx = '    '\r\n")]
+    //
+    // Blank lines are left alone
+    #[case("    a\n    \n    b", 4, "\ta\n    \n\tb")]
+    fn test_unexpand(#[case] input: &str, #[case] width: usize, #[case] expected: &str) {
+        let result = Unexpand::new(width).act(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_expand_unexpand_roundtrip(
+        #[values("", "a", "\ta\n\t\tb\n\tc", "def f():\n\tif x:\n\t\treturn '\t'\n")] input: &str,
+    ) {
+        let expanded = Expand::default().act(input);
+        let unexpanded = Unexpand::default().act(&expanded);
+
+        assert_eq!(unexpanded, input);
+    }
+}
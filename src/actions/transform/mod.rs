@@ -0,0 +1,225 @@
+use super::{Action, ActionError};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use clap::ValueEnum;
+use log::{error, info};
+use std::{error::Error, fmt, fmt::Write};
+
+/// Encodes or decodes input, e.g. from and to base64.
+///
+/// Leading and trailing whitespace of the input is kept as-is, only the part in
+/// between is transformed. That way, encoded tokens can be decoded in place, even if
+/// the scope includes, for example, a trailing newline.
+///
+/// ## Failure
+///
+/// Decoding can fail, for input that is not valid in the respective encoding, or does
+/// not decode to valid UTF-8. As an [`Action`] cannot fail, the input is then returned
+/// unchanged and an error is logged. Use [`Transform::try_act`] (or
+/// [`Action::act_checked`]) to handle failures instead.
+///
+/// ## Example: decoding base64 string contents
+///
+/// ```rust
+/// use srgn::RegexPattern;
+/// use srgn::actions::Transform;
+/// use srgn::scoping::{view::ScopedViewBuilder, regex::Regex};
+///
+/// let scoper = Regex::new(RegexPattern::new(r#"(?<=")[^"]*(?=")"#).unwrap());
+/// let mut builder = ScopedViewBuilder::new(r#"secret = "aGVsbG8=""#);
+/// builder.explode(&scoper);
+/// let mut view = builder.build();
+/// view.map(&Transform::Base64Decode);
+///
+/// assert_eq!(view.to_string(), r#"secret = "hello""#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Transform {
+    /// Encode to base64 (standard alphabet, with padding).
+    Base64Encode,
+    /// Decode from base64 (standard alphabet, with padding).
+    Base64Decode,
+    /// Encode to hexadecimal (lowercase), byte by byte.
+    HexEncode,
+    /// Decode from hexadecimal (any case).
+    HexDecode,
+    /// Rotate ASCII letters by 13 places. Its own inverse.
+    #[default]
+    Rot13,
+}
+
+/// An error that can occur when [transforming](Transform::try_act).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransformError {
+    /// The input is not valid base64.
+    InvalidBase64(String),
+    /// The input is not valid hexadecimal.
+    InvalidHex(String),
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8(String),
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBase64(input) => write!(f, "Not valid base64: '{input}'"),
+            Self::InvalidHex(input) => write!(f, "Not valid hexadecimal: '{input}'"),
+            Self::InvalidUtf8(input) => write!(f, "Does not decode to valid UTF-8: '{input}'"),
+        }
+    }
+}
+
+impl Error for TransformError {}
+
+impl Transform {
+    /// Apply this transform to the given input, failing on undecodable input.
+    ///
+    /// # Errors
+    ///
+    /// For decoding transforms, if the input is not valid in the respective encoding,
+    /// or if it does not decode to valid UTF-8.
+    pub fn try_act(&self, input: &str) -> Result<String, TransformError> {
+        let core = input.trim();
+        let start = input.len() - input.trim_start().len();
+        let end = start + core.len();
+
+        let transformed = match self {
+            Self::Base64Encode => BASE64.encode(core),
+            Self::Base64Decode => {
+                let bytes = BASE64
+                    .decode(core)
+                    .map_err(|_| TransformError::InvalidBase64(core.to_owned()))?;
+
+                String::from_utf8(bytes)
+                    .map_err(|_| TransformError::InvalidUtf8(core.to_owned()))?
+            }
+            Self::HexEncode => {
+                let mut res = String::with_capacity(2 * core.len());
+                for byte in core.bytes() {
+                    write!(res, "{byte:02x}").expect("writing to string cannot fail");
+                }
+
+                res
+            }
+            Self::HexDecode => {
+                let bytes =
+                    decode_hex(core).ok_or_else(|| TransformError::InvalidHex(core.to_owned()))?;
+
+                String::from_utf8(bytes)
+                    .map_err(|_| TransformError::InvalidUtf8(core.to_owned()))?
+            }
+            Self::Rot13 => core.chars().map(rot13).collect(),
+        };
+
+        Ok(format!("{}{transformed}{}", &input[..start], &input[end..]))
+    }
+}
+
+impl Action for Transform {
+    fn act(&self, input: &str) -> String {
+        info!("Transforming '{}' via {:?}", input.escape_debug(), self);
+
+        self.try_act(input).unwrap_or_else(|e| {
+            error!("{e}; leaving input unchanged");
+            input.to_owned()
+        })
+    }
+
+    fn act_checked(&self, input: &str) -> Result<String, ActionError> {
+        info!("Transforming '{}' via {:?}", input.escape_debug(), self);
+
+        self.try_act(input).map_err(Into::into)
+    }
+}
+
+fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    if input.len() % 2 != 0 {
+        return None;
+    }
+
+    input
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(pair, 16).ok()
+        })
+        .collect()
+}
+
+fn rot13(c: char) -> char {
+    match c {
+        'a'..='z' => rotate(c, b'a'),
+        'A'..='Z' => rotate(c, b'A'),
+        _ => c,
+    }
+}
+
+fn rotate(c: char, base: u8) -> char {
+    // Only ever called for ASCII letters, so cannot truncate.
+    #[allow(clippy::cast_possible_truncation)]
+    let offset = c as u8 - base;
+
+    char::from((offset + 13) % 26 + base)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Transform::Base64Encode, "hello", "aGVsbG8=")]
+    #[case(Transform::Base64Encode, "", "")]
+    #[case(Transform::Base64Encode, "Grüß 👋", "R3LDvMOfIPCfkYs=")]
+    #[case(Transform::Base64Encode, "  hello\n", "  aGVsbG8=\n")]
+    #[case(Transform::Base64Decode, "aGVsbG8=", "hello")]
+    #[case(Transform::Base64Decode, "R3LDvMOfIPCfkYs=\n", "Grüß 👋\n")]
+    #[case(Transform::HexEncode, "hi!", "686921")]
+    #[case(Transform::HexEncode, "ß", "c39f")]
+    #[case(Transform::HexDecode, "686921", "hi!")]
+    #[case(Transform::HexDecode, "C39F", "ß")]
+    #[case(Transform::Rot13, "Hello, World!", "Uryyb, Jbeyq!")]
+    #[case(Transform::Rot13, "Grüß 你好", "Teüß 你好")]
+    fn test_transform(#[case] transform: Transform, #[case] input: &str, #[case] expected: &str) {
+        assert_eq!(transform.act(input), expected);
+    }
+
+    #[rstest]
+    #[case(Transform::Base64Encode, Transform::Base64Decode)]
+    #[case(Transform::HexEncode, Transform::HexDecode)]
+    #[case(Transform::Rot13, Transform::Rot13)]
+    fn test_transform_roundtrip(
+        #[case] encode: Transform,
+        #[case] decode: Transform,
+        #[values(
+            "",
+            "hello",
+            "The quick brown fox jumps over the lazy dog.",
+            "Grüß Gott, 你好 👋🏻"
+        )]
+        input: &str,
+    ) {
+        let encoded = encode.try_act(input).unwrap();
+        let decoded = decode.try_act(&encoded).unwrap();
+
+        assert_eq!(decoded, input);
+    }
+
+    #[rstest]
+    #[case(Transform::Base64Decode, "not base64!", TransformError::InvalidBase64("not base64!".into()))]
+    #[case(Transform::Base64Decode, "/w==", TransformError::InvalidUtf8("/w==".into()))]
+    #[case(Transform::HexDecode, "abc", TransformError::InvalidHex("abc".into()))]
+    #[case(Transform::HexDecode, "zz", TransformError::InvalidHex("zz".into()))]
+    #[case(Transform::HexDecode, "ff", TransformError::InvalidUtf8("ff".into()))]
+    fn test_transform_failure(
+        #[case] transform: Transform,
+        #[case] input: &str,
+        #[case] expected: TransformError,
+    ) {
+        assert_eq!(transform.try_act(input), Err(expected));
+
+        // Infallible variant leaves input alone
+        assert_eq!(transform.act(input), input);
+    }
+}
@@ -0,0 +1,84 @@
+use super::Action;
+use log::info;
+
+/// Removes trailing whitespace (spaces and tabs) from each line.
+///
+/// Line endings are preserved, be they Unix- (`\n`) or DOS-style (`\r\n`). The last
+/// line is trimmed as well, even if it lacks a line ending.
+///
+/// As with any action, only what is in scope is touched. Without a scope, that is the
+/// entire input. Scoped to, say, comments only, trailing whitespace inside string
+/// literals is left alone.
+///
+/// ## Example: cleaning up a block of lines
+///
+/// ```rust
+/// use srgn::actions::{Action, TrimTrailing};
+///
+/// let action = TrimTrailing::default();
+/// let result = action.act("hello  \nworld\t\r\n  \n");
+///
+/// assert_eq!(result, "hello\nworld\r\n\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrimTrailing {}
+
+impl Action for TrimTrailing {
+    fn act(&self, input: &str) -> String {
+        info!("Trimming trailing whitespace of '{}'", input.escape_debug());
+
+        let mut res = String::with_capacity(input.len());
+
+        for line in input.split_inclusive('\n') {
+            let (content, line_ending) = if let Some(content) = line.strip_suffix("\r\n") {
+                (content, "\r\n")
+            } else if let Some(content) = line.strip_suffix('\n') {
+                (content, "\n")
+            } else {
+                (line, "")
+            };
+
+            res.push_str(content.trim_end_matches([' ', '\t']));
+            res.push_str(line_ending);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", "")]
+    #[case(" ", "")]
+    #[case("\n", "\n")]
+    #[case("a", "a")]
+    #[case("a ", "a")]
+    #[case(" a", " a")]
+    //
+    // Spaces and tabs, but nothing else
+    #[case("a \t \n", "a\n")]
+    #[case("a\u{a0}\n", "a\u{a0}\n")]
+    #[case("a\r", "a\r")]
+    //
+    // Line endings
+    #[case("a  \nb  ", "a\nb")]
+    #[case("a  \r\nb  \r\n", "a\r\nb\r\n")]
+    #[case("a \n\r\n \n", "a\n\r\n\n")]
+    //
+    // Realistic example
+    #[case(
+        "def f(x):   \n    \n    return x\t\n\n# done  \n",
+        "def f(x):\n\n    return x\n\n# done\n"
+    )]
+    fn test_trim_trailing(#[case] input: &str, #[case] expected: &str) {
+        let result = TrimTrailing::default().act(input);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,251 @@
+use super::Action;
+use log::info;
+
+/// The default maximum width of a line, in columns.
+const DEFAULT_WRAP_WIDTH: usize = 80;
+
+/// Reflows prose, wrapping lines to a maximum width.
+///
+/// Consecutive lines sharing the same prefix (indentation, followed by a line comment
+/// marker such as `//`, `#` or `*`, if any) form a paragraph. Words of a paragraph are
+/// joined and rewrapped, breaking on whitespace only, with each resulting line
+/// repeating the prefix. Words, and with them URLs, are never broken: words longer than
+/// the width end up on a line of their own. Lines without any text besides their
+/// prefix (e.g. blank lines) separate paragraphs and are left alone, as are line
+/// endings.
+///
+/// Width is counted in characters, including the prefix. As this action only sees
+/// its scope, any text preceding the scope on its first line is not accounted for.
+///
+/// ## Example: rewrapping a comment
+///
+/// ```rust
+/// use srgn::actions::{Action, Wrap};
+///
+/// let action = Wrap::new(20);
+/// let result = action.act("// A comment which is too long.\n//\n// Short.\n");
+///
+/// assert_eq!(result, "// A comment which\n// is too long.\n//\n// Short.\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wrap {
+    width: usize,
+}
+
+impl Wrap {
+    /// Create a new [`Wrap`], wrapping lines at `width` columns. A `width` of zero is
+    /// treated as one.
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Self {
+            width: width.max(1),
+        }
+    }
+
+    /// Lays out `words` into lines of at most `self.width` columns (where possible),
+    /// each starting with `prefix`, separated by `newline`.
+    fn wrap(self, prefix: &str, words: &[&str], newline: &str) -> String {
+        let prefix_width = prefix.chars().count();
+
+        let mut res = String::from(prefix);
+        let mut column = prefix_width;
+        let mut empty = true;
+
+        for word in words {
+            let width = word.chars().count();
+
+            if !empty && column + 1 + width > self.width {
+                res.push_str(newline);
+                res.push_str(prefix);
+                column = prefix_width;
+                empty = true;
+            }
+
+            if !empty {
+                res.push(' ');
+                column += 1;
+            }
+
+            res.push_str(word);
+            column += width;
+            empty = false;
+        }
+
+        res
+    }
+}
+
+impl Default for Wrap {
+    /// Wraps at 80 columns.
+    fn default() -> Self {
+        Self::new(DEFAULT_WRAP_WIDTH)
+    }
+}
+
+impl Action for Wrap {
+    fn act(&self, input: &str) -> String {
+        info!(
+            "Wrapping '{}' to width {}",
+            input.escape_debug(),
+            self.width
+        );
+
+        let mut res = String::with_capacity(input.len());
+        let mut lines = input.split_inclusive('\n').peekable();
+
+        while let Some(line) = lines.next() {
+            let (prefix, text) = split_prefix(line);
+
+            if text.trim().is_empty() {
+                res.push_str(line);
+                continue;
+            }
+
+            // Gather the entire paragraph, the last line of which determines how it
+            // ends.
+            let mut words: Vec<&str> = text.split_whitespace().collect();
+            let mut last = line;
+            while let Some(next) = lines.peek() {
+                let (next_prefix, next_text) = split_prefix(next);
+
+                if next_prefix != prefix || next_text.trim().is_empty() {
+                    break;
+                }
+
+                words.extend(next_text.split_whitespace());
+                last = next;
+                lines.next();
+            }
+
+            let newline = if line.ends_with("\r\n") { "\r\n" } else { "\n" };
+            let ending = &last[last.trim_end_matches(['\r', '\n']).len()..];
+
+            res.push_str(&self.wrap(prefix, &words, newline));
+            res.push_str(ending);
+        }
+
+        res
+    }
+}
+
+/// Splits a line into its prefix (indentation and any line comment marker, along with
+/// the whitespace following it) and the remaining text.
+fn split_prefix(line: &str) -> (&str, &str) {
+    let rest = line.trim_start_matches([' ', '\t']);
+
+    let after_marker = if rest.starts_with("//") {
+        rest.trim_start_matches('/').trim_start_matches('!')
+    } else if rest.starts_with('#') {
+        rest.trim_start_matches('#')
+    } else if rest.starts_with("--") {
+        rest.trim_start_matches('-')
+    } else if rest.starts_with(';') {
+        rest.trim_start_matches(';')
+    } else if rest.starts_with('*') && !rest.starts_with("*/") {
+        rest.trim_start_matches('*')
+    } else {
+        rest
+    };
+
+    let text = if after_marker.len() == rest.len() {
+        rest
+    } else {
+        after_marker.trim_start_matches([' ', '\t'])
+    };
+
+    line.split_at(line.len() - text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    // Base cases
+    #[case("", 40, "")]
+    #[case("a", 40, "a")]
+    #[case("a b c", 3, "a b\nc")]
+    #[case("a\n", 40, "a\n")]
+    //
+    // Reflowing: short lines are joined, long ones broken
+    #[case("a\nb\nc\n", 40, "a b c\n")]
+    #[case("aaa bbb ccc", 7, "aaa bbb\nccc")]
+    #[case("aaa  bbb\t\tccc  ", 40, "aaa bbb ccc")]
+    //
+    // Never breaks inside of words or URLs
+    #[case(
+        "see https://example.com/a/very/long/path",
+        10,
+        "see\nhttps://example.com/a/very/long/path"
+    )]
+    #[case("abcdefghij", 3, "abcdefghij")]
+    //
+    // Blank lines separate paragraphs, and are kept
+    #[case("a\nb\n\nc\nd\n", 40, "a b\n\nc d\n")]
+    #[case("a\n  \nb", 40, "a\n  \nb")]
+    //
+    // Prefixes are kept and repeated
+    #[case("    a b c", 7, "    a b\n    c")]
+    #[case("# a b c", 5, "# a b\n# c")]
+    #[case("/// a b c", 7, "/// a b\n/// c")]
+    #[case("//! a b c", 7, "//! a b\n//! c")]
+    #[case("-- a b c", 6, "-- a b\n-- c")]
+    #[case("; a b c", 5, "; a b\n; c")]
+    #[case(" * a b c", 6, " * a b\n * c")]
+    #[case("#a b c", 4, "#a b\n#c")]
+    //
+    // Differing prefixes separate paragraphs
+    #[case("# a\n  # b\n", 40, "# a\n  # b\n")]
+    #[case("// a\n//   b\n", 40, "// a\n//   b\n")]
+    //
+    // Marker-only lines separate paragraphs, and are kept
+    #[case("# a\n#\n# b\n", 40, "# a\n#\n# b\n")]
+    #[case("/**\n * a\n * b\n */", 40, "/**\n * a b\n */")]
+    //
+    // Line endings are kept
+    #[case("a b\r\nc\r\n", 3, "a b\r\nc\r\n")]
+    #[case("a\r\nb\r\n", 40, "a b\r\n")]
+    fn test_wrap(#[case] input: &str, #[case] width: usize, #[case] expected: &str) {
+        let result = Wrap::new(width).act(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_long_comment_at_width_40() {
+        let input = r"
+    // Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor
+    // incididunt ut labore et dolore magna aliqua. See
+    // https://en.wikipedia.org/wiki/Lorem_ipsum for more.
+    //
+    // Second paragraph.
+";
+        let expected = r"
+    // Lorem ipsum dolor sit amet,
+    // consectetur adipiscing elit, sed
+    // do eiusmod tempor incididunt ut
+    // labore et dolore magna aliqua.
+    // See
+    // https://en.wikipedia.org/wiki/Lorem_ipsum
+    // for more.
+    //
+    // Second paragraph.
+";
+
+        let result = Wrap::new(40).act(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_is_idempotent() {
+        let input = "// aaa bbb ccc ddd eee fff ggg\n// hhh\n";
+
+        let once = Wrap::new(12).act(input);
+        let twice = Wrap::new(12).act(&once);
+
+        assert_eq!(once, twice);
+    }
+}
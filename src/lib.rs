@@ -151,6 +151,7 @@
 #![warn(missing_docs)]
 #![allow(clippy::multiple_crate_versions)]
 #![allow(clippy::module_name_repetitions)]
+#![allow(clippy::uninlined_format_args)]
 
 #[cfg(doc)]
 use crate::{
@@ -1,15 +1,31 @@
+use anyhow::anyhow;
 use anyhow::Context;
 use anyhow::Result;
+use clap::ColorChoice;
 use log::{debug, error, info, warn, LevelFilter};
 use rayon::prelude::*;
+use serde::Deserialize;
+use srgn::actions::CollapseBlankLines;
 use srgn::actions::Deletion;
+use srgn::actions::Exec;
+use srgn::actions::Expand;
 #[cfg(feature = "german")]
 use srgn::actions::German;
 use srgn::actions::Lower;
 use srgn::actions::Normalization;
+use srgn::actions::NumberLines;
+use srgn::actions::Redact;
 use srgn::actions::Replacement;
+use srgn::actions::ReverseLines;
+use srgn::actions::SortLines;
+use srgn::actions::Surround;
 use srgn::actions::Titlecase;
+use srgn::actions::TrimTrailing;
+use srgn::actions::Unexpand;
 use srgn::actions::Upper;
+use srgn::actions::Wrap;
+use srgn::actions::{Escape, Unescape};
+use srgn::actions::{Indent, IndentUnit};
 #[cfg(feature = "symbols")]
 use srgn::actions::{Symbols, SymbolsInversion};
 use srgn::scoping::literal::LiteralError;
@@ -17,24 +33,38 @@ use srgn::scoping::regex::RegexError;
 use srgn::{
     actions::Action,
     scoping::{
+        exclusion::Exclusion,
+        indent::IndentBlock,
         langs::{
-            csharp::{CSharp, CSharpQuery},
-            go::{Go, GoQuery},
-            python::{Python, PythonQuery},
-            rust::{Rust, RustQuery},
-            typescript::{TypeScript, TypeScriptQuery},
+            comments_for_extension,
+            typescript::{
+                CustomTypeScriptQuery, PremadeTypeScriptQuery, TypeScript, TypeScriptQuery,
+            },
+            CodeQuery, Language, LanguageScoper, TSQuery,
         },
         literal::Literal,
         regex::Regex,
-        view::ScopedViewBuilder,
+        scope::{
+            RWScope, RWScopes,
+            Scope::{In, Out},
+        },
+        view::{Edit, ScopedViewBuilder},
         Scoper,
     },
 };
 use std::{
+    collections::HashMap,
     error::Error,
+    ffi::OsString,
     fmt,
     fs::File,
-    io::{self, IoSlice, Write},
+    io::{self, IoSlice, IsTerminal, Write},
+    path::Path,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
 };
 
 fn main() -> Result<()> {
@@ -64,6 +94,66 @@ fn main() -> Result<()> {
     let actions = assemble_actions(&args)?;
     debug!("Done assembling actions.");
 
+    if args.options.print_tree {
+        info!("Printing parse tree of stdin, not processing");
+        let input = io::read_to_string(io::stdin()).context("Failed reading stdin")?;
+
+        println!("{}", parse_tree(&args, &input)?);
+
+        return Ok(());
+    }
+
+    // `-` is stdout, same as no output file at all. A dry run (or showing scopes) only
+    // ever reports to stdout.
+    let reports_only = args.options.dry_run || args.options.show_scopes;
+    let output = args
+        .options
+        .output
+        .as_deref()
+        .filter(|path| path.as_os_str() != "-" && !reports_only);
+
+    let processing_options = ProcessingOptions {
+        fail_none: args.options.fail_none,
+        fail_any: args.options.fail_any,
+        squeeze: args.standalone_actions.squeeze,
+        dry_run: args.options.dry_run,
+        show_scopes: args.options.show_scopes,
+        no_newline: args.options.no_newline,
+        ensure_newline: args.options.ensure_newline,
+        // Files are written to, where escape codes have no business. Reports are not.
+        color: (args.options.files.is_none() || reports_only)
+            && output.is_none()
+            && match args.options.color.unwrap_or(ColorChoice::Auto) {
+                ColorChoice::Always => true,
+                ColorChoice::Never => false,
+                ColorChoice::Auto => io::stdout().is_terminal(),
+            },
+    };
+    let stats = args.options.stats.then(Stats::default);
+
+    let prompter = if args.options.interactive {
+        // When processing stdin, it's taken, so answers have to come from elsewhere.
+        let answers: Box<dyn io::BufRead + Send> = if args.options.files.is_some() {
+            Box::new(io::BufReader::new(io::stdin()))
+        } else {
+            let tty = File::open(TTY).context("Failed opening terminal to read answers from")?;
+            Box::new(io::BufReader::new(tty))
+        };
+
+        Some(Prompter::new(answers))
+    } else {
+        None
+    };
+
+    if let Some(threads) = args.options.threads {
+        info!("Using {} threads (0 meaning automatic)", threads);
+
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .context("Failed to configure thread pool")?;
+    }
+
     match &args.options.files {
         Some(pattern) => {
             info!("Will use glob pattern: {:?}", pattern);
@@ -83,6 +173,52 @@ fn main() -> Result<()> {
                         );
                     }
 
+                    if let Some(max) = args.options.max_filesize {
+                        let size = path
+                            .metadata()
+                            .with_context(|| format!("Failed to read metadata: {:?}", path))?
+                            .len();
+
+                        if size > max {
+                            warn!(
+                                "Skipping file exceeding maximum size ({} > {} bytes): '{}'",
+                                size,
+                                max,
+                                path.display()
+                            );
+
+                            return Ok(path);
+                        }
+                    }
+
+                    let language = if args.languages_scopes.comments {
+                        let extension = path
+                            .extension()
+                            .and_then(|ext| ext.to_str())
+                            .unwrap_or_default();
+
+                        let Some(scoper) = comments_for_extension(extension) else {
+                            warn!(
+                                "Skipping file of unsupported language: '{}'",
+                                path.display()
+                            );
+
+                            return Ok(path);
+                        };
+
+                        Some(scoper)
+                    } else {
+                        None
+                    };
+
+                    // Language-specific scoping has to come first, as it needs entire
+                    // files for context.
+                    let scopers = language
+                        .iter()
+                        .chain(&scopers)
+                        .map(AsRef::as_ref)
+                        .collect::<Vec<&dyn Scoper>>();
+
                     let contents = {
                         let file = File::open(&path)
                             .with_context(|| format!("Failed to read file: {:?}", path))?;
@@ -95,15 +231,28 @@ fn main() -> Result<()> {
                             &mut destination,
                             &scopers,
                             &actions,
-                            args.options.fail_none,
-                            args.options.fail_any,
-                            args.standalone_actions.squeeze,
+                            &processing_options,
+                            stats.as_ref(),
+                            prompter.as_ref(),
                         )
                         .with_context(|| format!("Failed to process file contents: {:?}", path))?;
 
                         destination.into_inner()
                     };
 
+                    if reports_only {
+                        let report = String::from_utf8(contents)
+                            .expect("Report is built from valid UTF-8 strings");
+
+                        let mut stdout = std::io::stdout().lock();
+                        for line in report.lines() {
+                            writeln!(stdout, "{}: {}", path.display(), line)
+                                .context("Failed writing report to stdout")?;
+                        }
+
+                        return Ok(path);
+                    }
+
                     debug!("Got new file contents, writing to file: {:?}", path);
                     let mut file = File::create(&path)
                         .with_context(|| format!("Failed to truncate file: {:?}", path))?;
@@ -132,23 +281,54 @@ fn main() -> Result<()> {
             }
         }
         None => {
-            info!("Will use stdin to stdout");
             let mut source = std::io::stdin().lock();
-            let mut destination = std::io::stdout().lock();
-
-            apply(
-                &mut source,
-                &mut destination,
-                &scopers,
-                &actions,
-                args.options.fail_none,
-                args.options.fail_any,
-                args.standalone_actions.squeeze,
-            )
-            .context("Failed to process stdin")?;
+            let scopers = scopers
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&dyn Scoper>>();
+
+            match output {
+                Some(path) => {
+                    info!("Will use stdin to file: {:?}", path);
+                    let mut destination = std::io::Cursor::new(Vec::new());
+
+                    apply(
+                        &mut source,
+                        &mut destination,
+                        &scopers,
+                        &actions,
+                        &processing_options,
+                        stats.as_ref(),
+                        prompter.as_ref(),
+                    )
+                    .context("Failed to process stdin")?;
+
+                    write_atomically(path, &destination.into_inner())
+                        .with_context(|| format!("Failed to write to file: {:?}", path))?;
+                }
+                None => {
+                    info!("Will use stdin to stdout");
+                    let mut destination = std::io::stdout().lock();
+
+                    apply(
+                        &mut source,
+                        &mut destination,
+                        &scopers,
+                        &actions,
+                        &processing_options,
+                        stats.as_ref(),
+                        prompter.as_ref(),
+                    )
+                    .context("Failed to process stdin")?;
+                }
+            }
         }
     }
 
+    if let Some(stats) = stats {
+        eprintln!("{stats}");
+    }
+
     info!("Done, exiting");
     Ok(())
 }
@@ -156,11 +336,11 @@ fn main() -> Result<()> {
 fn apply(
     source: &mut impl io::BufRead,
     destination: &mut impl io::Write,
-    scopers: &Vec<Box<dyn Scoper>>,
+    scopers: &[&dyn Scoper],
     actions: &Vec<Box<dyn Action>>,
-    fail_none: bool,
-    fail_any: bool,
-    squeeze: bool,
+    options: &ProcessingOptions,
+    stats: Option<&Stats>,
+    prompter: Option<&Prompter>,
 ) -> Result<()> {
     // Streaming (e.g., line-based) wouldn't be too bad, and much more memory-efficient,
     // but language grammar-aware scoping needs entire files for context. Single lines
@@ -176,33 +356,85 @@ fn apply(
     debug!("Building view.");
     let mut builder = ScopedViewBuilder::new(&buf);
     for scoper in scopers {
-        builder.explode(scoper);
+        builder.explode(*scoper);
     }
     let mut view = builder.build();
     debug!("Done building view: {view:?}");
 
-    if fail_none && !view.has_any_in_scope() {
+    if options.fail_none && !view.has_any_in_scope() {
         return Err(ApplicationError::NoneInScope.into());
     }
 
-    if fail_any && view.has_any_in_scope() {
+    if options.fail_any && view.has_any_in_scope() {
         return Err(ApplicationError::SomeInScope.into());
     };
 
+    if options.show_scopes {
+        debug!("Showing scopes instead of applying actions.");
+        destination
+            .write_all(mark_scopes(view.scopes()).as_bytes())
+            .context("Failed writing to destination")?;
+
+        return Ok(());
+    }
+
     debug!("Applying actions to view.");
-    let result = {
-        if squeeze {
+    let mut result = {
+        if options.squeeze {
             view.squeeze();
         }
 
+        let before = (stats.is_some() || options.color || options.dry_run || prompter.is_some())
+            .then(|| in_scope_parts(view.scopes()));
+
         for action in actions {
-            view.map(action);
+            view.try_map(action).map_err(|e| anyhow!(e))?;
+        }
+
+        let confirmed = match (prompter, &before) {
+            (Some(prompter), Some(before)) => {
+                Some(prompter.confirm(before, in_scope_parts(view.scopes()))?)
+            }
+            _ => None,
+        };
+
+        if let (Some(stats), Some(before)) = (stats, &before) {
+            match &confirmed {
+                Some(after) => stats.record(before, after),
+                None => stats.record(before, &in_scope_parts(view.scopes())),
+            }
         }
 
-        view.to_string()
+        match (before, confirmed) {
+            (Some(before), _) if options.dry_run => {
+                report(&before, &in_scope_parts(view.scopes()), options.color)
+            }
+            (_, Some(after)) => render(view.scopes(), &after),
+            // Without any actions, show what's in scope, else what changed.
+            (Some(before), None) if options.color => {
+                highlight(view.scopes(), &before, !actions.is_empty())
+            }
+            _ => view.to_string(),
+        }
     };
     debug!("Done applying actions to view.");
 
+    if !options.dry_run {
+        if options.no_newline {
+            let trimmed = result
+                .strip_suffix('\n')
+                .map(|rest| rest.strip_suffix('\r').unwrap_or(rest));
+
+            if let Some(trimmed) = trimmed {
+                result.truncate(trimmed.len());
+            }
+        }
+
+        if options.ensure_newline && !result.ends_with('\n') {
+            result.push('\n');
+        }
+    }
+
     debug!("Writing to destination.");
     destination
         .write_all(result.as_bytes())
@@ -212,11 +444,306 @@ fn apply(
     Ok(())
 }
 
+/// Write `contents` to `path` atomically: to a temporary file next to it first, which
+/// then replaces `path`. A failure midway therefore never leaves `path` half-written.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path has no file name"))?;
+
+    let mut tmp_name = OsString::from(".");
+    tmp_name.push(file_name);
+    tmp_name.push(format!(".{}.tmp", std::process::id()));
+    let tmp = path.with_file_name(tmp_name);
+
+    debug!("Writing to temporary file: {:?}", tmp);
+    let res = File::create(&tmp)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|()| std::fs::rename(&tmp, path));
+
+    if res.is_err() {
+        // Best effort only; the original error is what matters.
+        if let Err(e) = std::fs::remove_file(&tmp) {
+            warn!("Failed to remove temporary file {:?}: {}", tmp, e);
+        }
+    }
+
+    res
+}
+
+/// Options governing how each input is processed.
+#[derive(Debug, Clone, Copy)]
+struct ProcessingOptions {
+    fail_none: bool,
+    fail_any: bool,
+    squeeze: bool,
+    color: bool,
+    dry_run: bool,
+    show_scopes: bool,
+    no_newline: bool,
+    ensure_newline: bool,
+}
+
+/// Report the in-scope parts which changed, one `"before" -> "after"` line each.
+///
+/// If `color`, both parts are highlighted using ANSI escape codes.
+fn report(before: &[String], after: &[String], color: bool) -> String {
+    debug_assert_eq!(before.len(), after.len(), "Actions cannot change scopes");
+
+    let quote = |part: &String| {
+        if color {
+            format!("{HIGHLIGHT_START}{part:?}{HIGHLIGHT_END}")
+        } else {
+            format!("{part:?}")
+        }
+    };
+
+    before
+        .iter()
+        .zip(after)
+        .filter(|(old, new)| old != new)
+        .map(|(old, new)| format!("{} -> {}\n", quote(old), quote(new)))
+        .collect()
+}
+
+const SCOPE_START: &str = "«";
+const SCOPE_END: &str = "»";
+
+/// Render `scopes`, wrapping each in-scope part in markers.
+fn mark_scopes(scopes: &RWScopes) -> String {
+    let mut res = String::new();
+
+    for scope in &scopes.0 {
+        match scope {
+            RWScope(In(s)) => {
+                res.push_str(SCOPE_START);
+                res.push_str(s);
+                res.push_str(SCOPE_END);
+            }
+            RWScope(Out(s)) => res.push_str(s),
+        }
+    }
+
+    res
+}
+
+const HIGHLIGHT_START: &str = "\x1b[1;31m";
+const HIGHLIGHT_END: &str = "\x1b[0m";
+
+/// Render `scopes`, highlighting in-scope parts using ANSI escape codes.
+///
+/// If `only_changes`, only the parts that changed compared to `before` (the in-scope
+/// parts prior to processing) are highlighted, instead of entire in-scope parts.
+fn highlight(scopes: &RWScopes, before: &[String], only_changes: bool) -> String {
+    let mut res = String::new();
+    let mut before = before.iter();
+
+    for scope in &scopes.0 {
+        match scope {
+            RWScope(In(new)) => {
+                let old = before.next().expect("Actions cannot change scopes");
+
+                if !only_changes {
+                    push_highlighted(&mut res, new);
+                    continue;
+                }
+
+                match Edit::minimal(0, old, new) {
+                    Some(edit) => {
+                        let start = edit.byte_range.start;
+                        let end = start + edit.new_text.len();
+
+                        res.push_str(&new[..start]);
+                        push_highlighted(&mut res, &new[start..end]);
+                        res.push_str(&new[end..]);
+                    }
+                    None => res.push_str(new),
+                }
+            }
+            RWScope(Out(s)) => res.push_str(s),
+        }
+    }
+
+    res
+}
+
+fn push_highlighted(res: &mut String, s: &str) {
+    if !s.is_empty() {
+        res.push_str(HIGHLIGHT_START);
+        res.push_str(s);
+        res.push_str(HIGHLIGHT_END);
+    }
+}
+
+/// Render `scopes`, with in-scope parts replaced by `parts`, in order.
+fn render(scopes: &RWScopes, parts: &[String]) -> String {
+    let mut parts = parts.iter();
+
+    scopes
+        .0
+        .iter()
+        .map(|scope| match scope {
+            RWScope(In(_)) => parts.next().expect("Actions cannot change scopes").as_str(),
+            RWScope(Out(s)) => s,
+        })
+        .collect()
+}
+
+/// The terminal to read answers from in interactive mode, if stdin is taken.
+#[cfg(windows)]
+const TTY: &str = "CONIN$";
+#[cfg(not(windows))]
+const TTY: &str = "/dev/tty";
+
+/// Asks whether to apply each change, much like `git add --patch`.
+///
+/// Inputs are processed in parallel, but prompts of one input are not interleaved with
+/// another's.
+struct Prompter {
+    state: Mutex<PrompterState>,
+}
+
+struct PrompterState {
+    answers: Box<dyn io::BufRead + Send>,
+    /// Decision for all remaining changes, once made.
+    remaining: Option<bool>,
+}
+
+impl Prompter {
+    fn new(answers: Box<dyn io::BufRead + Send>) -> Self {
+        Self {
+            state: Mutex::new(PrompterState {
+                answers,
+                remaining: None,
+            }),
+        }
+    }
+
+    /// Ask about each in-scope part changed from `before` to `after`, returning `after`
+    /// with declined changes reverted. Prompts go to stderr. Running out of answers
+    /// declines all remaining changes.
+    fn confirm(&self, before: &[String], mut after: Vec<String>) -> Result<Vec<String>> {
+        debug_assert_eq!(before.len(), after.len(), "Actions cannot change scopes");
+
+        let mut state = self.state.lock().expect("No panics while holding lock");
+        let mut stderr = io::stderr().lock();
+
+        for (old, new) in before.iter().zip(after.iter_mut()) {
+            if old == new {
+                continue;
+            }
+
+            let accept = match state.remaining {
+                Some(decision) => decision,
+                None => loop {
+                    write!(
+                        stderr,
+                        "-{old:?}\n+{new:?}\nApply this change [y,n,a,q,?]? "
+                    )?;
+                    stderr.flush()?;
+
+                    let mut answer = String::new();
+                    if state.answers.read_line(&mut answer)? == 0 {
+                        writeln!(stderr)?;
+                        state.remaining = Some(false);
+                        break false;
+                    }
+
+                    match answer.trim() {
+                        "y" => break true,
+                        "n" => break false,
+                        "a" => {
+                            state.remaining = Some(true);
+                            break true;
+                        }
+                        "q" => {
+                            state.remaining = Some(false);
+                            break false;
+                        }
+                        _ => writeln!(
+                            stderr,
+                            "y - apply this change\n\
+                             n - do not apply this change\n\
+                             a - apply this and all remaining changes\n\
+                             q - do not apply this or any remaining change\n\
+                             ? - print help"
+                        )?,
+                    }
+                },
+            };
+
+            if !accept {
+                new.clone_from(old);
+            }
+        }
+
+        Ok(after)
+    }
+}
+
+fn in_scope_parts(scopes: &RWScopes) -> Vec<String> {
+    scopes
+        .0
+        .iter()
+        .filter_map(|scope| match scope {
+            RWScope(In(s)) => Some(s.to_string()),
+            RWScope(Out(_)) => None,
+        })
+        .collect()
+}
+
+/// Counters for a summary of what processing did, across all inputs.
+///
+/// Atomic, as files are processed in parallel.
+#[derive(Debug, Default)]
+struct Stats {
+    scopes: AtomicUsize,
+    scopes_changed: AtomicUsize,
+    bytes_changed: AtomicUsize,
+}
+
+impl Stats {
+    /// Record the in-scope parts of some input, `before` and `after` processing.
+    fn record(&self, before: &[String], after: &[String]) {
+        debug_assert_eq!(before.len(), after.len(), "Actions cannot change scopes");
+
+        self.scopes.fetch_add(before.len(), Ordering::Relaxed);
+
+        for (old, new) in before.iter().zip(after) {
+            let Some(edit) = Edit::minimal(0, old, new) else {
+                continue;
+            };
+
+            self.scopes_changed.fetch_add(1, Ordering::Relaxed);
+            self.bytes_changed.fetch_add(
+                edit.byte_range.len().max(edit.new_text.len()),
+                Ordering::Relaxed,
+            );
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stats: scopes={} changed={} bytes_changed={}",
+            self.scopes.load(Ordering::Relaxed),
+            self.scopes_changed.load(Ordering::Relaxed),
+            self.bytes_changed.load(Ordering::Relaxed),
+        )
+    }
+}
+
 #[derive(Debug)]
 enum ApplicationError {
     SomeInScope,
     NoneInScope,
     EmptyGlob(glob::Pattern),
+    NoLanguage,
 }
 
 impl fmt::Display for ApplicationError {
@@ -228,6 +755,7 @@ impl fmt::Display for ApplicationError {
             ),
             Self::NoneInScope => write!(f, "Nothing in scope and explicit failure requested."),
             Self::EmptyGlob(p) => write!(f, "No files matched glob pattern: {:?}", p),
+            Self::NoLanguage => write!(f, "No language selected."),
         }
     }
 }
@@ -265,80 +793,166 @@ impl fmt::Display for ScoperBuildError {
 
 impl Error for ScoperBuildError {}
 
+/// Render the parse tree of `input`, as parsed by the grammar of the selected language.
+fn parse_tree(args: &cli::Cli, input: &str) -> Result<String> {
+    let Some(language) = args.languages_scopes.language() else {
+        return Err(ApplicationError::NoLanguage).context("Cannot print parse tree");
+    };
+
+    Ok(language.parse_tree(input))
+}
+
 fn assemble_scopers(args: &cli::Cli) -> Result<Vec<Box<dyn Scoper>>> {
     let mut scopers: Vec<Box<dyn Scoper>> = Vec::new();
 
-    if let Some(csharp) = args.languages_scopes.csharp.clone() {
-        if let Some(premade) = csharp.csharp {
-            let query = CSharpQuery::Premade(premade);
+    // Refers to line numbers of the entire input, so goes first.
+    if let Some(line) = args.options.indent_block {
+        scopers.push(Box::new(IndentBlock::new(line)));
+    }
 
-            scopers.push(Box::new(CSharp::new(query)));
-        } else if let Some(custom) = csharp.csharp_query {
-            let query = CSharpQuery::Custom(custom);
+    if let Some(language) = args.languages_scopes.language() {
+        scopers.extend(language.scoper(args)?);
+    }
 
-            scopers.push(Box::new(CSharp::new(query)));
-        }
+    if args.options.literal_string {
+        scopers.push(Box::new(
+            Literal::try_from(args.scope.clone()).context("Failed building literal string")?,
+        ));
+    } else {
+        scopers.push(Box::new(
+            Regex::try_from(args.scope.clone()).context("Failed building regex")?,
+        ));
     }
 
-    if let Some(go) = args.languages_scopes.go.clone() {
-        if let Some(premade) = go.go {
-            let query = GoQuery::Premade(premade);
+    if let Some(exclude) = args.options.exclude.clone() {
+        scopers.push(Box::new(Exclusion::new(
+            Regex::try_from(exclude).context("Failed building exclusion regex")?,
+        )));
+    }
 
-            scopers.push(Box::new(Go::new(query)));
-        } else if let Some(custom) = go.go_query {
-            let query = GoQuery::Custom(custom);
+    Ok(scopers)
+}
 
-            scopers.push(Box::new(Go::new(query)));
-        }
-    }
+/// Configuration file contents, holding named queries for reuse.
+///
+/// ```toml
+/// [typescript.queries]
+/// exported_functions = "(export_statement (function_declaration) @f)"
+/// ```
+#[derive(Debug, Deserialize)]
+struct Config {
+    /// Per-language configuration, by language name.
+    #[serde(flatten)]
+    languages: HashMap<String, LanguageConfig>,
+}
 
-    if let Some(python) = args.languages_scopes.python.clone() {
-        if let Some(premade) = python.python {
-            let query = PythonQuery::Premade(premade);
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LanguageConfig {
+    /// Custom queries, by name.
+    #[serde(default)]
+    queries: HashMap<String, String>,
+}
 
-            scopers.push(Box::new(Python::new(query)));
-        } else if let Some(custom) = python.python_query {
-            let query = PythonQuery::Custom(custom);
+impl Config {
+    fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {:?}", path))?;
 
-            scopers.push(Box::new(Python::new(query)));
-        }
+        toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file: {:?}", path))
     }
 
-    if let Some(rust) = args.languages_scopes.rust.clone() {
-        if let Some(premade) = rust.rust {
-            let query = RustQuery::Premade(premade);
+    /// The query of `language` named `name`.
+    fn query(&self, language: &str, name: &str) -> Result<&str> {
+        self.languages
+            .get(language)
+            .and_then(|config| config.queries.get(name))
+            .map(String::as_str)
+            .with_context(|| format!("No {language} query named '{name}' in config file"))
+    }
+}
 
-            scopers.push(Box::new(Rust::new(query)));
-        } else if let Some(custom) = rust.rust_query {
-            let query = RustQuery::Custom(custom);
+/// The command line arguments scoping a single language.
+trait LanguageScope {
+    /// The scoper for the query passed on the command line, if any.
+    fn scoper(&self, args: &cli::Cli) -> Result<Option<Box<dyn Scoper>>>;
 
-            scopers.push(Box::new(Rust::new(query)));
-        }
-    }
+    /// Render the parse tree of `input`, as parsed by the grammar of the language.
+    fn parse_tree(&self, input: &str) -> String;
+}
 
-    if let Some(typescript) = args.languages_scopes.typescript.clone() {
-        if let Some(premade) = typescript.typescript {
-            let query = TypeScriptQuery::Premade(premade);
+/// The scoper for the `premade` query, or else for the `custom` one, if any.
+fn language_scoper<C, P>(
+    premade: Option<P>,
+    custom: Option<C>,
+    args: &cli::Cli,
+) -> Result<Option<Box<dyn Scoper>>>
+where
+    C: Clone + FromStr + Into<TSQuery> + 'static,
+    P: Clone + Into<TSQuery> + 'static,
+    Language<CodeQuery<C, P>>: Scoper,
+{
+    let scoper: Box<dyn Scoper> = if let Some(premade) = premade {
+        Box::new(Language::new(CodeQuery::Premade(premade)))
+    } else if let Some(custom) = custom {
+        Box::new(select_capture(
+            Language::new(CodeQuery::Custom(custom)),
+            args,
+        )?)
+    } else {
+        return Ok(None);
+    };
 
-            scopers.push(Box::new(TypeScript::new(query)));
-        } else if let Some(custom) = typescript.typescript_query {
-            let query = TypeScriptQuery::Custom(custom);
+    Ok(Some(scoper))
+}
 
-            scopers.push(Box::new(TypeScript::new(query)));
+impl LanguageScope for cli::TypeScriptScope {
+    fn scoper(&self, args: &cli::Cli) -> Result<Option<Box<dyn Scoper>>> {
+        if let Some(premade) = self.typescript {
+            let query = match (premade, &args.tagged_comments_options.comment_tags) {
+                (PremadeTypeScriptQuery::TaggedComments, Some(tags)) => {
+                    TypeScriptQuery::Custom(CustomTypeScriptQuery::tagged_comments(tags)?)
+                }
+                _ => TypeScriptQuery::Premade(premade),
+            };
+
+            return Ok(Some(Box::new(TypeScript::new(query))));
         }
+
+        let custom = if let Some(custom) = self.typescript_query.clone() {
+            custom
+        } else if let Some(name) = &self.typescript_query_name {
+            let config = Config::load(&args.options.config)?;
+            CustomTypeScriptQuery::from_str(config.query("typescript", name)?)
+                .with_context(|| format!("Invalid TypeScript query named '{name}'"))?
+        } else {
+            return Ok(None);
+        };
+        let query = TypeScriptQuery::Custom(custom);
+
+        Ok(Some(Box::new(select_capture(
+            TypeScript::new(query),
+            args,
+        )?)))
     }
 
-    if args.options.literal_string {
-        scopers.push(Box::new(
-            Literal::try_from(args.scope.clone()).context("Failed building literal string")?,
-        ));
-    } else {
-        scopers.push(Box::new(
-            Regex::try_from(args.scope.clone()).context("Failed building regex")?,
-        ));
+    fn parse_tree(&self, input: &str) -> String {
+        TypeScript::parse_tree(input)
     }
+}
 
-    Ok(scopers)
+/// Restrict a language scoper to the capture requested on the command line, if any.
+fn select_capture<Q>(lang: Language<Q>, args: &cli::Cli) -> Result<Language<Q>>
+where
+    Q: Clone + Into<TSQuery>,
+{
+    match &args.options.capture {
+        Some(name) => lang
+            .with_capture(name)
+            .context("Failed selecting query capture"),
+        None => Ok(lang),
+    }
 }
 
 fn assemble_actions(args: &cli::Cli) -> Result<Vec<Box<dyn Action>>> {
@@ -353,11 +967,42 @@ fn assemble_actions(args: &cli::Cli) -> Result<Vec<Box<dyn Action>>> {
 
     #[cfg(feature = "german")]
     if args.composable_actions.german {
-        actions.push(Box::new(German::new(
-            // Smell? Bug if bools swapped.
-            args.german_options.german_prefer_original,
-            args.german_options.german_naive,
-        )));
+        let mut german = if let Some(path) = &args.german_options.german_word_list {
+            German::from_wordlist_path(path)
+                .with_context(|| format!("Failed loading word list from {}", path.display()))?
+        } else if cfg!(feature = "german-word-lists") {
+            German::default()
+        } else {
+            return Err(anyhow!(
+                "No German word lists compiled in, pass one via --german-word-list"
+            ));
+        };
+        if args.german_options.german_prefer_original {
+            german.prefer_original();
+        }
+        if args.german_options.german_naive {
+            german.naive();
+        }
+        if let Some(len) = args.german_options.german_min_word_len {
+            german.min_word_len(len);
+        }
+        german.eszett_rule(args.german_options.german_eszett_rule);
+        german.name_heuristic(args.german_options.german_name_heuristic);
+        german.region(args.german_options.german_region);
+        german.fuzzy(args.german_options.german_fuzzy);
+        german.historical(args.german_options.german_historical);
+        if !args.german_options.german_only.is_empty() {
+            german.enabled_replacements(args.german_options.german_only.iter().copied().collect());
+        }
+        german.never_correct(
+            args.german_options
+                .german_never_correct
+                .iter()
+                .cloned()
+                .collect(),
+        );
+
+        actions.push(Box::new(german));
         debug!("Loaded action: German");
     }
 
@@ -373,8 +1018,16 @@ fn assemble_actions(args: &cli::Cli) -> Result<Vec<Box<dyn Action>>> {
     }
 
     if args.standalone_actions.delete {
-        actions.push(Box::<Deletion>::default());
-        debug!("Loaded action: Deletion");
+        if let Some(placeholder) = args.delete_options.replace_empty_with.clone() {
+            // Deleting, then inserting a placeholder amounts to replacing.
+            actions.push(Box::new(
+                Replacement::try_from(placeholder).context("Failed building placeholder string")?,
+            ));
+            debug!("Loaded action: Deletion (with placeholder)");
+        } else {
+            actions.push(Box::<Deletion>::default());
+            debug!("Loaded action: Deletion");
+        }
     }
 
     if args.composable_actions.upper {
@@ -397,7 +1050,137 @@ fn assemble_actions(args: &cli::Cli) -> Result<Vec<Box<dyn Action>>> {
         debug!("Loaded action: Normalization");
     }
 
-    if actions.is_empty() && !(args.options.fail_any || args.options.fail_none) {
+    if args.composable_actions.sort_lines {
+        actions.push(Box::new(SortLines::new(
+            args.sort_lines_options.sort_lines_case_insensitive,
+            args.sort_lines_options.sort_lines_reverse,
+            args.sort_lines_options.sort_lines_unique,
+        )));
+        debug!("Loaded action: SortLines");
+    }
+
+    if args.composable_actions.reverse_lines {
+        actions.push(Box::<ReverseLines>::default());
+        debug!("Loaded action: ReverseLines");
+    }
+
+    if let Some(transform) = args.composable_actions.transform {
+        actions.push(Box::new(transform));
+        debug!("Loaded action: Transform");
+    }
+
+    if let Some(mode) = args.composable_actions.escape {
+        actions.push(Box::new(Escape::new(mode)));
+        debug!("Loaded action: Escape");
+    }
+
+    if let Some(mode) = args.composable_actions.unescape {
+        actions.push(Box::new(Unescape::new(mode)));
+        debug!("Loaded action: Unescape");
+    }
+
+    if let Some(levels) = args.composable_actions.indent {
+        let unit = if args.indent_options.indent_tabs {
+            IndentUnit::Tab
+        } else {
+            args.indent_options
+                .indent_width
+                .map_or_else(IndentUnit::default, IndentUnit::Spaces)
+        };
+
+        actions.push(Box::new(Indent::new(unit, levels)));
+        debug!("Loaded action: Indent");
+    }
+
+    if args.composable_actions.expand {
+        let action = args
+            .tabs_options
+            .tab_width
+            .map_or_else(Expand::default, Expand::new);
+        actions.push(Box::new(action));
+        debug!("Loaded action: Expand");
+    }
+
+    if args.composable_actions.unexpand {
+        let action = args
+            .tabs_options
+            .tab_width
+            .map_or_else(Unexpand::default, Unexpand::new);
+        actions.push(Box::new(action));
+        debug!("Loaded action: Unexpand");
+    }
+
+    if args.composable_actions.wrap {
+        let action = args
+            .wrap_options
+            .wrap_width
+            .map_or_else(Wrap::default, Wrap::new);
+        actions.push(Box::new(action));
+        debug!("Loaded action: Wrap");
+    }
+
+    if args.composable_actions.trim_trailing {
+        actions.push(Box::<TrimTrailing>::default());
+        debug!("Loaded action: TrimTrailing");
+    }
+
+    if let Some(command) = &args.composable_actions.exec {
+        actions.push(Box::new(Exec::shell(command.clone())));
+        debug!("Loaded action: Exec");
+    }
+
+    if args.composable_actions.collapse_blank_lines {
+        let action = args
+            .collapse_blank_lines_options
+            .max_blank_lines
+            .map_or_else(CollapseBlankLines::default, CollapseBlankLines::new);
+        actions.push(Box::new(action));
+        debug!("Loaded action: CollapseBlankLines");
+    }
+
+    if args.composable_actions.number_lines {
+        let options = &args.number_lines_options;
+        let mut action = NumberLines::default();
+
+        if let Some(start) = options.number_start {
+            action.start(start);
+        }
+
+        if let Some(step) = options.number_step {
+            action.step(step);
+        }
+
+        if let Some(format) = &options.number_format {
+            action.format(format.clone());
+        }
+
+        actions.push(Box::new(action));
+        debug!("Loaded action: NumberLines");
+    }
+
+    if let Some(mode) = args.composable_actions.redact {
+        let mut action = Redact::new(mode);
+
+        if let Some(n) = args.redact_options.redact_keep {
+            action.keep(n);
+        }
+
+        actions.push(Box::new(action));
+        debug!("Loaded action: Redact");
+    }
+
+    // Run last, such that no other action touches the added prefix and suffix.
+    if args.composable_actions.prepend.is_some() || args.composable_actions.append.is_some() {
+        actions.push(Box::new(Surround::new(
+            args.composable_actions.prepend.clone().unwrap_or_default(),
+            args.composable_actions.append.clone().unwrap_or_default(),
+        )));
+        debug!("Loaded action: Surround");
+    }
+
+    if actions.is_empty()
+        && !(args.options.fail_any || args.options.fail_none || args.options.show_scopes)
+    {
         // Doesn't hurt, but warn loudly
         error!("No actions loaded, will return input unchanged");
     }
@@ -428,19 +1211,70 @@ fn level_filter_from_env_and_verbosity(additional_verbosity: u8) -> LevelFilter
     })
 }
 
+/// Parse a file size, either plain bytes or with a binary unit suffix (`K`, `M`, `G`),
+/// e.g. `10M`.
+fn parse_filesize(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let (digits, multiplier) = match s.char_indices().last() {
+        Some((i, 'k' | 'K')) => (&s[..i], 1 << 10),
+        Some((i, 'm' | 'M')) => (&s[..i], 1 << 20),
+        Some((i, 'g' | 'G')) => (&s[..i], 1 << 30),
+        _ => (s, 1),
+    };
+
+    digits
+        .parse::<u64>()
+        .map_err(|e| format!("Invalid file size '{s}': {e}"))?
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("File size '{s}' too large"))
+}
+
+/// Parse a single comment tag, which cannot be empty: it would match all comments.
+fn parse_comment_tag(s: &str) -> Result<String, String> {
+    if s.is_empty() {
+        return Err("Comment tags cannot be empty".into());
+    }
+
+    Ok(s.to_owned())
+}
+
 mod cli {
-    use clap::{builder::ArgPredicate, ArgAction, Command, CommandFactory, Parser};
+    use super::{language_scoper, LanguageScope, Result};
+    use clap::{builder::ArgPredicate, ArgAction, ColorChoice, Command, CommandFactory, Parser};
     use clap_complete::{generate, Generator, Shell};
+    #[cfg(feature = "german")]
+    use srgn::actions::{Region, ReplacementKind};
+    use srgn::scoping::{langs::LanguageScoper, Scoper};
     use srgn::{
+        actions::{EscapeMode, RedactMode, Transform},
         scoping::langs::{
-            csharp::{CustomCSharpQuery, PremadeCSharpQuery},
-            go::{CustomGoQuery, PremadeGoQuery},
-            python::{CustomPythonQuery, PremadePythonQuery},
-            rust::{CustomRustQuery, PremadeRustQuery},
+            asm::{Asm, CustomAsmQuery, PremadeAsmQuery},
+            clojure::{Clojure, CustomClojureQuery, PremadeClojureQuery},
+            csharp::{CSharp, CustomCSharpQuery, PremadeCSharpQuery},
+            elixir::{CustomElixirQuery, Elixir, PremadeElixirQuery},
+            fortran::{CustomFortranQuery, Fortran, PremadeFortranQuery},
+            go::{CustomGoQuery, Go, PremadeGoQuery},
+            graphql::{CustomGraphqlQuery, Graphql, PremadeGraphqlQuery},
+            groovy::{CustomGroovyQuery, Groovy, PremadeGroovyQuery},
+            haskell::{CustomHaskellQuery, Haskell, PremadeHaskellQuery},
+            hcl::{CustomHclQuery, Hcl, PremadeHclQuery},
+            ini::{CustomIniQuery, Ini, PremadeIniQuery},
+            julia::{CustomJuliaQuery, Julia, PremadeJuliaQuery},
+            make::{CustomMakeQuery, Make, PremadeMakeQuery},
+            ocaml::{CustomOcamlQuery, Ocaml, PremadeOcamlQuery},
+            perl::{CustomPerlQuery, Perl, PremadePerlQuery},
+            proto::{CustomProtoQuery, PremadeProtoQuery, Proto},
+            python::{CustomPythonQuery, PremadePythonQuery, Python},
+            r::{CustomRQuery, PremadeRQuery, R},
+            rust::{CustomRustQuery, PremadeRustQuery, Rust},
+            scala::{CustomScalaQuery, PremadeScalaQuery, Scala},
+            tsx::{CustomTsxQuery, PremadeTsxQuery, Tsx},
             typescript::{CustomTypeScriptQuery, PremadeTypeScriptQuery},
+            zig::{CustomZigQuery, PremadeZigQuery, Zig},
         },
         GLOBAL_SCOPE,
     };
+    use std::path::PathBuf;
 
     /// Main CLI entrypoint.
     ///
@@ -489,6 +1323,33 @@ mod cli {
         #[cfg(feature = "german")]
         #[command(flatten)]
         pub german_options: GermanOptions,
+
+        #[command(flatten)]
+        pub sort_lines_options: SortLinesOptions,
+
+        #[command(flatten)]
+        pub indent_options: IndentOptions,
+
+        #[command(flatten)]
+        pub tabs_options: TabsOptions,
+
+        #[command(flatten)]
+        pub delete_options: DeleteOptions,
+
+        #[command(flatten)]
+        pub tagged_comments_options: TaggedCommentsOptions,
+
+        #[command(flatten)]
+        pub wrap_options: WrapOptions,
+
+        #[command(flatten)]
+        pub collapse_blank_lines_options: CollapseBlankLinesOptions,
+
+        #[command(flatten)]
+        pub number_lines_options: NumberLinesOptions,
+
+        #[command(flatten)]
+        pub redact_options: RedactOptions,
     }
 
     /// https://github.com/clap-rs/clap/blob/f65d421607ba16c3175ffe76a20820f123b6c4cb/clap_complete/examples/completion-derive.rs#L69
@@ -510,9 +1371,70 @@ mod cli {
         /// Names of processed files are written to stdout.
         #[arg(long, verbatim_doc_comment)]
         pub files: Option<glob::Pattern>,
+        /// Write output to this file instead of stdout ('-' for stdout)
+        ///
+        /// The file is replaced atomically: on failure, an existing file is left
+        /// untouched, unlike with shell redirection ('> file').
+        #[arg(
+            short,
+            long,
+            value_name = "FILE",
+            conflicts_with = "files",
+            verbatim_doc_comment
+        )]
+        pub output: Option<PathBuf>,
+        /// Do not modify anything, but report what would change.
+        ///
+        /// For each part of scope that actions would change, prints a line of the
+        /// form '"before" -> "after"' to stdout (prefixed by the file name, if
+        /// processing files). Files are left untouched.
+        #[arg(long, verbatim_doc_comment)]
+        pub dry_run: bool,
+        /// Ask before applying each change.
+        ///
+        /// For each part of scope that actions would change, shows it before and after,
+        /// and asks whether to apply the change (y/n), this and all remaining ones (a),
+        /// or none of the remaining ones (q). Answers are read from the terminal, or
+        /// from stdin when processing files.
+        #[arg(long, conflicts_with = "dry_run", verbatim_doc_comment)]
+        pub interactive: bool,
+        /// Do not modify anything, but show what is in scope.
+        ///
+        /// Prints the input with each part of scope wrapped in '«' and '»', to stdout
+        /// (prefixed by the file name, if processing files). Actions are not applied,
+        /// and files are left untouched. A quick way to check what a query matches.
+        #[arg(
+            long,
+            conflicts_with_all = ["dry_run", "interactive"],
+            verbatim_doc_comment
+        )]
+        pub show_scopes: bool,
+        /// Strip a single trailing line ending ('\n' or '\r\n') from output, if any.
+        #[arg(long, conflicts_with = "ensure_newline", verbatim_doc_comment)]
+        pub no_newline: bool,
+        /// Add a trailing line ending ('\n') to output, if missing.
+        #[arg(long, verbatim_doc_comment)]
+        pub ensure_newline: bool,
         /// Fail if file globbing is requested but returns no matches.
         #[arg(long, verbatim_doc_comment, requires = "files")]
         pub fail_empty_glob: bool,
+        /// Skip files larger than this (with a warning) instead of processing them.
+        ///
+        /// Either plain bytes, or with a binary unit suffix: '10K', '10M', '1G'.
+        #[arg(
+            long,
+            value_name = "SIZE",
+            value_parser = super::parse_filesize,
+            requires = "files",
+            verbatim_doc_comment
+        )]
+        pub max_filesize: Option<u64>,
+        /// Number of threads to process files with.
+        ///
+        /// '1' processes files sequentially. '0' (the default) picks a number
+        /// automatically, based on the available CPU cores.
+        #[arg(long, value_name = "N", requires = "files", verbatim_doc_comment)]
+        pub threads: Option<usize>,
         /// Undo the effects of passed actions, where applicable
         ///
         /// Requires a 1:1 mapping (bijection) between replacements and original, which
@@ -536,6 +1458,47 @@ mod cli {
         /// string. Will require a scope to be passed.
         #[arg(short('L'), long, env, verbatim_doc_comment)]
         pub literal_string: bool,
+        /// Exclude parts matching this regular expression from scope
+        ///
+        /// Applied last, to each part otherwise in scope, such that '^' and '$' anchor
+        /// to the start and end of such a part. For example, language strings include
+        /// their quotes, so '--typescript strings --exclude "^.https?://"' skips the
+        /// start of URL-looking strings, and '^.https?://.*' skips them entirely.
+        #[arg(long, value_name = "PATTERN", verbatim_doc_comment)]
+        pub exclude: Option<String>,
+        /// Only scope the lines following line LINE (1-based) which are indented deeper
+        /// than it
+        ///
+        /// Selects an indentation-defined block, such as a function body in Python, as
+        /// a whole. Blank lines within the block are part of it. Applied first, so
+        /// other scopes are narrowed down further.
+        #[arg(long, value_name = "LINE", verbatim_doc_comment)]
+        pub indent_block: Option<usize>,
+        /// Only scope to the capture of this name in a custom language query.
+        ///
+        /// By default, all captures of a query are in scope. Useful for queries with
+        /// multiple captures, e.g. '@key' and '@value', to pick one of them.
+        ///
+        /// Fails if the query has no capture of that name.
+        #[arg(long, value_name = "NAME", verbatim_doc_comment)]
+        pub capture: Option<String>,
+        /// Configuration file to read named queries from
+        ///
+        /// Named queries are defined per language, for example:
+        ///
+        ///     [typescript.queries]
+        ///     exported_functions = "(export_statement (function_declaration) @f)"
+        ///
+        /// and are used by name, e.g. '--typescript-query-name exported_functions'.
+        /// Only read if a named query is used.
+        #[arg(
+            long,
+            env = "SRGN_CONFIG",
+            value_name = "PATH",
+            default_value = ".srgn.toml",
+            verbatim_doc_comment
+        )]
+        pub config: PathBuf,
         /// If anything at all is found to be in scope, fail.
         ///
         /// The default is to continue processing normally.
@@ -546,6 +1509,26 @@ mod cli {
         /// The default is to return the input unchanged (without failure).
         #[arg(long, verbatim_doc_comment)]
         pub fail_none: bool,
+        /// Print the parse tree of stdin to stdout instead of processing it.
+        ///
+        /// Uses the grammar of the selected language (e.g. '--typescript'), showing
+        /// named nodes with their byte ranges. Useful for debugging custom queries.
+        #[arg(long, verbatim_doc_comment)]
+        pub print_tree: bool,
+        /// Print a summary of processing to stderr when done.
+        ///
+        /// Reports the number of scopes, how many of them changed and how many bytes
+        /// changed.
+        #[arg(long, verbatim_doc_comment)]
+        pub stats: bool,
+        /// Highlight scope in output, using ANSI escape codes
+        ///
+        /// Without any actions, highlights what is in scope. Otherwise, highlights what
+        /// changed. With '--dry-run', highlights the reported parts. 'auto' (the
+        /// default) highlights if stdout is a terminal. Only applies when writing to
+        /// stdout, never to files.
+        #[arg(long, value_name = "WHEN", verbatim_doc_comment)]
+        pub color: Option<ColorChoice>,
         /// Increase log verbosity level
         ///
         /// The base log level to use is read from the `RUST_LOG` environment variable
@@ -583,6 +1566,122 @@ mod cli {
         /// Normalize (Normalization Form D) scope, and throw away marks
         #[arg(short, long, env, verbatim_doc_comment)]
         pub normalize: bool,
+        /// Sort lines within scope
+        ///
+        /// Scopes need to span multiple lines for this to have an effect (e.g. use
+        /// '(?s).*' as the scope, or a language scope).
+        ///
+        /// Line endings, including a trailing one, are preserved.
+        #[arg(
+            long,
+            verbatim_doc_comment,
+            default_value_if("sort-lines-opts", ArgPredicate::IsPresent, "true")
+        )]
+        pub sort_lines: bool,
+        /// Reverse the order of lines within scope
+        ///
+        /// Scopes need to span multiple lines for this to have an effect (e.g. use
+        /// '(?s).*' as the scope, or a language scope).
+        ///
+        /// Line endings, including a trailing one, are preserved.
+        #[arg(long, verbatim_doc_comment)]
+        pub reverse_lines: bool,
+        /// Encode or decode scope, e.g. from base64
+        ///
+        /// Surrounding whitespace of scope is kept as-is. Scope which fails to decode
+        /// is left unchanged, with an error logged.
+        #[arg(long, value_name = "MODE", verbatim_doc_comment)]
+        pub transform: Option<Transform>,
+        /// Escape scope for use inside of strings of some syntax
+        ///
+        /// Scope string contents only, without their quotes. Quotes, backslashes and
+        /// control characters are escaped for JSON, '"', '\', '$' and '`' for
+        /// (double-quoted) shell strings, and metacharacters for regular expressions.
+        #[arg(long, value_name = "MODE", verbatim_doc_comment)]
+        pub escape: Option<EscapeMode>,
+        /// Unescape scope from strings of some syntax, the inverse of '--escape'
+        ///
+        /// Invalid escape sequences are left as-is.
+        #[arg(long, value_name = "MODE", verbatim_doc_comment)]
+        pub unescape: Option<EscapeMode>,
+        /// Redact scope, e.g. to sanitize secrets from logs
+        ///
+        /// Scope is replaced by a fixed number of asterisks, hiding its length, or by
+        /// the first 8 hex digits of its SHA-256 digest, allowing occurrences to be
+        /// correlated. Alternatively, only characters in between its first and last
+        /// few are masked (see '--redact-keep').
+        #[arg(long, value_name = "MODE", verbatim_doc_comment)]
+        pub redact: Option<RedactMode>,
+        /// Shift lines within scope by this many levels of indentation
+        ///
+        /// Negative values remove indentation. Blank lines are left alone. Scopes
+        /// should span entire lines (e.g. use '(?s).*' as the scope, or a language
+        /// scope).
+        #[arg(
+            long,
+            value_name = "LEVELS",
+            allow_negative_numbers = true,
+            verbatim_doc_comment
+        )]
+        pub indent: Option<isize>,
+        /// Convert leading tabs to spaces within scope
+        ///
+        /// Tabs advance to the next tab stop (see '--tab-width'). Only leading
+        /// indentation is affected, tabs elsewhere are left alone.
+        #[arg(long, conflicts_with = "unexpand", verbatim_doc_comment)]
+        pub expand: bool,
+        /// Convert leading spaces to tabs within scope
+        ///
+        /// Spaces not filling up an entire tab (see '--tab-width') are kept. Only
+        /// leading indentation is affected, spaces elsewhere are left alone.
+        #[arg(long, verbatim_doc_comment)]
+        pub unexpand: bool,
+        /// Reflow prose within scope, wrapping lines to a maximum width
+        ///
+        /// Consecutive lines with the same indentation and line comment marker (like
+        /// '//' or '#') are rewrapped together, breaking on whitespace only (see
+        /// '--wrap-width'). Words, such as URLs, are never broken. Use with a comments
+        /// scope to rewrap documentation.
+        #[arg(long, verbatim_doc_comment)]
+        pub wrap: bool,
+        /// Remove trailing whitespace (spaces, tabs) from each line within scope
+        ///
+        /// Line endings are preserved. Without a scope, cleans the entire input.
+        #[arg(long, verbatim_doc_comment)]
+        pub trim_trailing: bool,
+        /// Collapse runs of consecutive blank lines within scope
+        ///
+        /// Runs longer than the maximum (see '--max-blank-lines') are shortened to it.
+        /// Leading and trailing blank lines are collapsed, not removed. Scopes should
+        /// span multiple lines (e.g. use '(?s).*' as the scope).
+        #[arg(long, verbatim_doc_comment)]
+        pub collapse_blank_lines: bool,
+        /// Prefix each line within scope with its number, like 'cat -n'
+        ///
+        /// Numbering starts afresh for each part of scope (see '--number-start',
+        /// '--number-step' and '--number-format'). To number lines across the entire
+        /// input, scope it as a whole (e.g. use '(?s).*' as the scope).
+        #[arg(long, verbatim_doc_comment)]
+        pub number_lines: bool,
+        /// Pipe each part of scope through this shell command, replacing it with the
+        /// command's output
+        ///
+        /// The command is run once per part of scope (through 'sh -c', or 'cmd /C' on
+        /// Windows), with the part fed to its stdin. Its stdout is used verbatim,
+        /// including any trailing newline. Processing aborts if the command fails to
+        /// run or exits unsuccessfully.
+        #[arg(long, value_name = "COMMAND", verbatim_doc_comment)]
+        pub exec: Option<String>,
+        /// Prepend this (fixed) value to scope
+        ///
+        /// Will run after any other action, such that the value is not modified.
+        #[arg(long, value_name = "PREFIX", verbatim_doc_comment)]
+        pub prepend: Option<String>,
+        /// Append this (fixed) value to scope
+        ///
+        /// Will run after any other action, such that the value is not modified.
+        #[arg(long, value_name = "SUFFIX", verbatim_doc_comment)]
+        pub append: Option<String>,
         /// Perform substitutions on German words, such as 'Abenteuergruesse' to
         /// 'Abenteuergrüße'
         ///
@@ -640,68 +1739,251 @@ mod cli {
         pub squeeze: bool,
     }
 
-    #[derive(Parser, Debug)]
-    #[group(required = false, multiple = false)]
-    #[command(next_help_heading = "Language scopes")]
-    pub(super) struct LanguageScopes {
-        #[command(flatten)]
-        pub csharp: Option<CSharpScope>,
-        #[command(flatten)]
-        pub go: Option<GoScope>,
-        #[command(flatten)]
-        pub python: Option<PythonScope>,
-        #[command(flatten)]
-        pub rust: Option<RustScope>,
-        #[command(flatten)]
-        pub typescript: Option<TypeScriptScope>,
+    /// Defines the language scopes, one `$field` per language `$scope`, of which at most
+    /// one can be selected.
+    macro_rules! language_scopes {
+        ($($field:ident: $scope:ident),* $(,)?) => {
+            #[derive(Parser, Debug)]
+            #[group(required = false, multiple = false)]
+            #[command(next_help_heading = "Language scopes")]
+            pub(super) struct LanguageScopes {
+                $(
+                    #[command(flatten)]
+                    pub $field: Option<$scope>,
+                )*
+                /// Scope comments, in whichever language each file is written in
+                ///
+                /// The language is detected from the file extension, using the respective
+                /// premade query for comments. Files of unsupported languages are skipped
+                /// (with a warning). Useful for mixed-language code bases.
+                #[arg(long, requires = "files", verbatim_doc_comment)]
+                pub comments: bool,
+            }
+
+            impl LanguageScopes {
+                /// The scope of the selected language, if any.
+                pub fn language(&self) -> Option<&dyn LanguageScope> {
+                    [$(self.$field.as_ref().map(|scope| scope as &dyn LanguageScope)),*]
+                        .into_iter()
+                        .flatten()
+                        .next()
+                }
+            }
+        };
     }
 
-    #[derive(Parser, Debug, Clone)]
-    #[group(required = false, multiple = false)]
-    pub(super) struct CSharpScope {
-        /// Scope CSharp code using a premade query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub csharp: Option<PremadeCSharpQuery>,
+    /// Defines the arguments scoping the language `$lang` of the given display
+    /// `$name`, via either a premade or a custom query.
+    macro_rules! language_scope {
+        (
+            $scope:ident($lang:ident, $name:literal) {
+                $premade:ident: $premade_query:ident,
+                $custom:ident: $custom_query:ident $(,)?
+            }
+        ) => {
+            #[derive(Parser, Debug, Clone)]
+            #[group(required = false, multiple = false)]
+            pub(super) struct $scope {
+                #[arg(
+                    long,
+                    env,
+                    help = concat!("Scope ", $name, " code using a premade query."),
+                    verbatim_doc_comment
+                )]
+                pub $premade: Option<$premade_query>,
+
+                #[arg(
+                    long,
+                    env,
+                    help = concat!("Scope ", $name, " code using a custom tree-sitter query."),
+                    verbatim_doc_comment
+                )]
+                pub $custom: Option<$custom_query>,
+            }
 
-        /// Scope CSharp code using a custom tree-sitter query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub csharp_query: Option<CustomCSharpQuery>,
+            impl LanguageScope for $scope {
+                fn scoper(&self, args: &Cli) -> Result<Option<Box<dyn Scoper>>> {
+                    language_scoper(self.$premade.clone(), self.$custom.clone(), args)
+                }
+
+                fn parse_tree(&self, input: &str) -> String {
+                    $lang::parse_tree(input)
+                }
+            }
+        };
     }
 
-    #[derive(Parser, Debug, Clone)]
-    #[group(required = false, multiple = false)]
-    pub(super) struct GoScope {
-        /// Scope Go code using a premade query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub go: Option<PremadeGoQuery>,
+    language_scopes! {
+        asm: AsmScope,
+        clojure: ClojureScope,
+        csharp: CSharpScope,
+        elixir: ElixirScope,
+        fortran: FortranScope,
+        go: GoScope,
+        graphql: GraphqlScope,
+        groovy: GroovyScope,
+        haskell: HaskellScope,
+        hcl: HclScope,
+        ini: IniScope,
+        julia: JuliaScope,
+        make: MakeScope,
+        ocaml: OcamlScope,
+        perl: PerlScope,
+        proto: ProtoScope,
+        python: PythonScope,
+        r: RScope,
+        rust: RustScope,
+        scala: ScalaScope,
+        tsx: TsxScope,
+        typescript: TypeScriptScope,
+        zig: ZigScope,
+    }
 
-        /// Scope Go code using a custom tree-sitter query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub go_query: Option<CustomGoQuery>,
+    language_scope! {
+        AsmScope(Asm, "Assembly") {
+            asm: PremadeAsmQuery,
+            asm_query: CustomAsmQuery,
+        }
     }
 
-    #[derive(Parser, Debug, Clone)]
-    #[group(required = false, multiple = false)]
-    pub(super) struct PythonScope {
-        /// Scope Python code using a premade query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub python: Option<PremadePythonQuery>,
+    language_scope! {
+        ClojureScope(Clojure, "Clojure") {
+            clojure: PremadeClojureQuery,
+            clojure_query: CustomClojureQuery,
+        }
+    }
 
-        /// Scope Python code using a custom tree-sitter query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub python_query: Option<CustomPythonQuery>,
+    language_scope! {
+        CSharpScope(CSharp, "CSharp") {
+            csharp: PremadeCSharpQuery,
+            csharp_query: CustomCSharpQuery,
+        }
     }
 
-    #[derive(Parser, Debug, Clone)]
-    #[group(required = false, multiple = false)]
-    pub(super) struct RustScope {
-        /// Scope Rust code using a premade query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub rust: Option<PremadeRustQuery>,
+    language_scope! {
+        ElixirScope(Elixir, "Elixir") {
+            elixir: PremadeElixirQuery,
+            elixir_query: CustomElixirQuery,
+        }
+    }
 
-        /// Scope Rust code using a custom tree-sitter query.
-        #[arg(long, env, verbatim_doc_comment)]
-        pub rust_query: Option<CustomRustQuery>,
+    language_scope! {
+        FortranScope(Fortran, "Fortran") {
+            fortran: PremadeFortranQuery,
+            fortran_query: CustomFortranQuery,
+        }
+    }
+
+    language_scope! {
+        GoScope(Go, "Go") {
+            go: PremadeGoQuery,
+            go_query: CustomGoQuery,
+        }
+    }
+
+    language_scope! {
+        GraphqlScope(Graphql, "GraphQL") {
+            graphql: PremadeGraphqlQuery,
+            graphql_query: CustomGraphqlQuery,
+        }
+    }
+
+    language_scope! {
+        GroovyScope(Groovy, "Groovy") {
+            groovy: PremadeGroovyQuery,
+            groovy_query: CustomGroovyQuery,
+        }
+    }
+
+    language_scope! {
+        HaskellScope(Haskell, "Haskell") {
+            haskell: PremadeHaskellQuery,
+            haskell_query: CustomHaskellQuery,
+        }
+    }
+
+    language_scope! {
+        HclScope(Hcl, "HCL") {
+            hcl: PremadeHclQuery,
+            hcl_query: CustomHclQuery,
+        }
+    }
+
+    language_scope! {
+        IniScope(Ini, "INI") {
+            ini: PremadeIniQuery,
+            ini_query: CustomIniQuery,
+        }
+    }
+
+    language_scope! {
+        JuliaScope(Julia, "Julia") {
+            julia: PremadeJuliaQuery,
+            julia_query: CustomJuliaQuery,
+        }
+    }
+
+    language_scope! {
+        MakeScope(Make, "Makefile") {
+            make: PremadeMakeQuery,
+            make_query: CustomMakeQuery,
+        }
+    }
+
+    language_scope! {
+        OcamlScope(Ocaml, "OCaml") {
+            ocaml: PremadeOcamlQuery,
+            ocaml_query: CustomOcamlQuery,
+        }
+    }
+
+    language_scope! {
+        PerlScope(Perl, "Perl") {
+            perl: PremadePerlQuery,
+            perl_query: CustomPerlQuery,
+        }
+    }
+
+    language_scope! {
+        ProtoScope(Proto, "Protobuf") {
+            proto: PremadeProtoQuery,
+            proto_query: CustomProtoQuery,
+        }
+    }
+
+    language_scope! {
+        PythonScope(Python, "Python") {
+            python: PremadePythonQuery,
+            python_query: CustomPythonQuery,
+        }
+    }
+
+    language_scope! {
+        RScope(R, "R") {
+            r: PremadeRQuery,
+            r_query: CustomRQuery,
+        }
+    }
+
+    language_scope! {
+        RustScope(Rust, "Rust") {
+            rust: PremadeRustQuery,
+            rust_query: CustomRustQuery,
+        }
+    }
+
+    language_scope! {
+        ScalaScope(Scala, "Scala") {
+            scala: PremadeScalaQuery,
+            scala_query: CustomScalaQuery,
+        }
+    }
+
+    language_scope! {
+        TsxScope(Tsx, "TSX") {
+            tsx: PremadeTsxQuery,
+            tsx_query: CustomTsxQuery,
+        }
     }
 
     #[derive(Parser, Debug, Clone)]
@@ -714,6 +1996,24 @@ mod cli {
         /// Scope TypeScript code using a custom tree-sitter query.
         #[arg(long, env, verbatim_doc_comment)]
         pub typescript_query: Option<CustomTypeScriptQuery>,
+
+        /// Scope TypeScript code using a custom tree-sitter query of this name, as
+        /// defined in the configuration file (see '--config').
+        #[arg(
+            long,
+            env,
+            visible_alias = "ts-query-name",
+            value_name = "NAME",
+            verbatim_doc_comment
+        )]
+        pub typescript_query_name: Option<String>,
+    }
+
+    language_scope! {
+        ZigScope(Zig, "Zig") {
+            zig: PremadeZigQuery,
+            zig_query: CustomZigQuery,
+        }
     }
 
     #[cfg(feature = "german")]
@@ -737,6 +2037,212 @@ mod cli {
         /// dictionaries. Called 'naive' as this does not perform legal checks.
         #[arg(long, env, verbatim_doc_comment)]
         pub german_naive: bool,
+        /// Leave words shorter than this many characters untouched
+        ///
+        /// Very short tokens like a lone 'ss' or 'ae' are unlikely to be meaningful
+        /// words. Defaults to 1, considering all words.
+        #[arg(long, env, value_name = "N", verbatim_doc_comment)]
+        pub german_min_word_len: Option<usize>,
+        /// For words unknown to the word list, replace 'ss' by 'ß' after long vowels
+        /// and diphthongs, per spelling rule
+        ///
+        /// Only diphthongs ('ei', 'au', ...), 'ie' and doubled vowels are detectable
+        /// as long: for single long vowels, as in 'Fuß', the word list has to decide.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub german_eszett_rule: bool,
+        /// Do not replace 'ss' by 'ß' in names, such as 'Strauss' in 'Herr Strauss'
+        ///
+        /// Heuristic: a capitalized word directly following a title ('Herr', 'Frau',
+        /// 'Dr.', ...) is considered a name. Umlauts are still replaced.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub german_name_heuristic: bool,
+        /// Never correct this word, e.g. a proper noun or brand name ('Boeing')
+        ///
+        /// Matching is case-insensitive. Can be given multiple times.
+        #[arg(long, value_name = "WORD", verbatim_doc_comment)]
+        pub german_never_correct: Vec<String>,
+        /// Regional word list to validate words against
+        ///
+        /// Regions share a common word list, and add their own vocabulary on top (e.g.
+        /// 'Jänner' in Austria). In Switzerland, 'ss' is never replaced by 'ß'.
+        /// Regional lists only cover a handful of common words; see
+        /// '--german-word-list' for full coverage.
+        #[arg(long, env, value_enum, default_value_t, verbatim_doc_comment)]
+        pub german_region: Region,
+        /// Also try swapping adjacent letters of unknown words, to fix typos such as
+        /// 'Breucke' (for 'Brücke')
+        ///
+        /// Only swaps of 'e' with a neighboring 'a', 'o' or 'u' are tried, and only
+        /// results containing an Umlaut and found in the word list are accepted.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub german_fuzzy: bool,
+        /// Also try modernizing historical spellings of unknown words, such as 'Thür'
+        /// (for 'Tür')
+        ///
+        /// Only a small set of spellings is mapped: 'ſ' to 's', 'th' to 't', 'ey' to
+        /// 'ei' and 'dt' to 't'. Only results found in the word list are accepted.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub german_historical: bool,
+        /// Only attempt these kinds of replacements, leaving others alone
+        ///
+        /// For example, '--german-only ss' fixes 'ss' to 'ß' but leaves 'ae', 'oe'
+        /// and 'ue' alone. Can be given multiple times, or comma-separated. Defaults to
+        /// all kinds.
+        #[arg(
+            long,
+            value_enum,
+            value_name = "KIND",
+            value_delimiter = ',',
+            verbatim_doc_comment
+        )]
+        pub german_only: Vec<ReplacementKind>,
+        /// Validate words against this word list instead of the built-in ones
+        ///
+        /// A plain text file, one word per line. Regional vocabulary (see
+        /// '--german-region') does not apply. Required if srgn was built without
+        /// the 'german-word-lists' feature.
+        #[arg(long, env, value_name = "PATH", verbatim_doc_comment)]
+        pub german_word_list: Option<PathBuf>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("sort-lines-opts"))]
+    #[command(next_help_heading = "Options (sort-lines)")]
+    pub(super) struct SortLinesOptions {
+        /// Compare lines disregarding their case.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub sort_lines_case_insensitive: bool,
+        /// Sort lines in descending order.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub sort_lines_reverse: bool,
+        /// Drop duplicate lines, keeping the first occurrence.
+        #[arg(long, env, verbatim_doc_comment)]
+        pub sort_lines_unique: bool,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("indent-opts"))]
+    #[command(next_help_heading = "Options (indent)")]
+    pub(super) struct IndentOptions {
+        /// Indent using tabs instead of spaces.
+        #[arg(
+            long,
+            requires = "indent",
+            conflicts_with = "indent_width",
+            verbatim_doc_comment
+        )]
+        pub indent_tabs: bool,
+        /// Number of spaces making up one level of indentation. Defaults to 4.
+        #[arg(long, value_name = "N", requires = "indent", verbatim_doc_comment)]
+        pub indent_width: Option<usize>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("delete-opts"))]
+    #[command(next_help_heading = "Options (delete)")]
+    pub(super) struct DeleteOptions {
+        /// Instead of removing each part of scope entirely, leave this placeholder
+        ///
+        /// Useful for redacting, e.g. replacing all comments (using a language scope) by
+        /// a marker. Escape sequences are processed, as for REPLACEMENT.
+        #[arg(
+            long,
+            value_name = "PLACEHOLDER",
+            requires = "delete",
+            verbatim_doc_comment
+        )]
+        pub replace_empty_with: Option<String>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("tabs-opts"))]
+    #[command(next_help_heading = "Options (expand, unexpand)")]
+    pub(super) struct TabsOptions {
+        /// Width of a tab, in columns. Defaults to 4.
+        #[arg(long, value_name = "N", verbatim_doc_comment)]
+        pub tab_width: Option<usize>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("tagged-comments-opts"))]
+    #[command(next_help_heading = "Options (tagged comments)")]
+    pub(super) struct TaggedCommentsOptions {
+        /// Tags to look for in comments, replacing the defaults (TODO, FIXME, HACK, XXX).
+        ///
+        /// Only applies to 'tagged-comments' premade queries. Comma-separated.
+        #[arg(
+            long,
+            value_name = "TAGS",
+            value_delimiter = ',',
+            value_parser = super::parse_comment_tag,
+            verbatim_doc_comment
+        )]
+        pub comment_tags: Option<Vec<String>>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("wrap-opts"))]
+    #[command(next_help_heading = "Options (wrap)")]
+    pub(super) struct WrapOptions {
+        /// Maximum width of a line, in characters. Defaults to 80.
+        #[arg(long, value_name = "N", requires = "wrap", verbatim_doc_comment)]
+        pub wrap_width: Option<usize>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("collapse-blank-lines-opts"))]
+    #[command(next_help_heading = "Options (collapse blank lines)")]
+    pub(super) struct CollapseBlankLinesOptions {
+        /// Maximum number of consecutive blank lines to keep. Defaults to 1; 0 removes
+        /// blank lines entirely.
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "collapse_blank_lines",
+            verbatim_doc_comment
+        )]
+        pub max_blank_lines: Option<usize>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("number-lines-opts"))]
+    #[command(next_help_heading = "Options (number lines)")]
+    pub(super) struct NumberLinesOptions {
+        /// Number of the first line of each part of scope. Defaults to 1.
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "number_lines",
+            verbatim_doc_comment
+        )]
+        pub number_start: Option<usize>,
+        /// Amount to count up by for each line. Defaults to 1.
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "number_lines",
+            verbatim_doc_comment
+        )]
+        pub number_step: Option<usize>,
+        /// Format of the prefix, with every '{n}' replaced by the line number.
+        /// Defaults to '{n} '.
+        #[arg(
+            long,
+            value_name = "FORMAT",
+            requires = "number_lines",
+            verbatim_doc_comment
+        )]
+        pub number_format: Option<String>,
+    }
+
+    #[derive(Parser, Debug)]
+    #[group(required = false, multiple = true, id("redact-opts"))]
+    #[command(next_help_heading = "Options (redact)")]
+    pub(super) struct RedactOptions {
+        /// Number of characters to keep at either end when redacting partially.
+        /// Defaults to 4.
+        #[arg(long, value_name = "N", requires = "redact", verbatim_doc_comment)]
+        pub redact_keep: Option<usize>,
     }
 
     impl Cli {
@@ -829,4 +2335,22 @@ mod tests {
         let result = level_filter_from_env_and_verbosity(additional_verbosity);
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    #[case("0", Ok(0))]
+    #[case("123", Ok(123))]
+    #[case(" 123 ", Ok(123))]
+    #[case("1k", Ok(1024))]
+    #[case("10K", Ok(10 * 1024))]
+    #[case("10M", Ok(10 * 1024 * 1024))]
+    #[case("2g", Ok(2 * 1024 * 1024 * 1024))]
+    #[case("", Err(()))]
+    #[case("M", Err(()))]
+    #[case("-1", Err(()))]
+    #[case("1.5M", Err(()))]
+    #[case("10T", Err(()))]
+    #[case("18446744073709551615K", Err(()))]
+    fn test_parse_filesize(#[case] input: &str, #[case] expected: Result<u64, ()>) {
+        assert_eq!(parse_filesize(input).map_err(|_| ()), expected);
+    }
 }
@@ -0,0 +1,87 @@
+use super::{ROScopes, Scoper};
+use log::trace;
+
+/// Excludes from scope whatever the wrapped scoper finds.
+///
+/// Applied after other scopers, this computes a set difference: whatever is currently
+/// in scope, *minus* the parts the wrapped scoper finds within it. The wrapped scoper
+/// sees each in-scope part on its own, so, for example, a regex anchor `^` matches at
+/// the start of each such part.
+///
+/// ## Example: all strings, except URLs
+///
+/// ```rust
+/// use srgn::RegexPattern;
+/// use srgn::scoping::{exclusion::Exclusion, regex::Regex, view::ScopedViewBuilder};
+/// use srgn::scoping::langs::typescript::{
+///     PremadeTypeScriptQuery, TypeScript, TypeScriptQuery,
+/// };
+///
+/// let strings = TypeScript::new(TypeScriptQuery::Premade(PremadeTypeScriptQuery::Strings));
+/// // Strings include their quotes, hence the leading `.`.
+/// let urls = Exclusion::new(Regex::new(RegexPattern::new(r"^.https?://.*").unwrap()));
+///
+/// let mut builder = ScopedViewBuilder::new(r#"f("https://example.com", "hello");"#);
+/// builder.explode(&strings).explode(&urls);
+/// let mut view = builder.build();
+/// view.upper();
+///
+/// assert_eq!(view.to_string(), r#"f("https://example.com", "HELLO");"#);
+/// ```
+#[derive(Debug)]
+pub struct Exclusion<S> {
+    scoper: S,
+}
+
+impl<S> Exclusion<S> {
+    /// Create a new [`Exclusion`], excluding whatever `scoper` finds.
+    #[must_use]
+    pub fn new(scoper: S) -> Self {
+        Self { scoper }
+    }
+}
+
+impl<S> Scoper for Exclusion<S>
+where
+    S: Scoper,
+{
+    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
+        trace!("Excluding from scope: {:?}", input);
+
+        self.scoper.scope(input).invert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::scoping::{regex::Regex, view::ScopedViewBuilder};
+    use crate::RegexPattern;
+
+    use super::*;
+
+    #[rstest]
+    #[case("hello world", r"\w+", "o", "____o _o___")]
+    #[case("hello world", r"\w+", "x", "_____ _____")]
+    #[case("hello world", r"\w+", r"\w+", "hello world")]
+    #[case("hello world", r"\w+", "^h", "h____ _____")]
+    #[case("hello world", r"(?s).*", "^h", "h__________")]
+    #[case("a1 b2 c3", r"\w+", r"\d", "_1 _2 _3")]
+    fn test_exclusion(
+        #[case] input: &str,
+        #[case] scope: &str,
+        #[case] exclude: &str,
+        #[case] expected: &str,
+    ) {
+        let mut builder = ScopedViewBuilder::new(input);
+        builder.explode(&Regex::new(RegexPattern::new(scope).unwrap()));
+        builder.explode(&Exclusion::new(Regex::new(
+            RegexPattern::new(exclude).unwrap(),
+        )));
+        let mut view = builder.build();
+        view.map(&|s: &str| "_".repeat(s.chars().count()));
+
+        assert_eq!(view.to_string(), expected);
+    }
+}
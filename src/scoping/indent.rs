@@ -0,0 +1,166 @@
+use super::{ROScopes, Scoper};
+use log::trace;
+use std::ops::Range;
+
+/// Scopes the block of lines indented deeper than a reference line.
+///
+/// The block consists of the contiguous lines following the reference line which are
+/// indented deeper than it, such as the body of a Python function following its
+/// signature. Blank lines do not end the block, but trailing ones are not part of it.
+/// Lines are scoped entirely, including their line endings.
+///
+/// Indentation is compared by its number of characters (spaces and tabs alike), so
+/// mixing tabs and spaces is not supported. If the reference line does not exist or is
+/// blank, nothing is in scope. Line numbers refer to the input this scoper sees, so
+/// apply it first for them to match line numbers of the entire input.
+///
+/// ## Example: scoping a function body
+///
+/// ```rust
+/// use srgn::scoping::{indent::IndentBlock, view::ScopedViewBuilder};
+///
+/// let input = "def f():\n    return 1\n\ndef g():\n    return 2\n";
+///
+/// let mut builder = ScopedViewBuilder::new(input);
+/// builder.explode(&IndentBlock::new(4));
+/// let mut view = builder.build();
+/// view.upper();
+///
+/// assert_eq!(
+///     view.to_string(),
+///     "def f():\n    return 1\n\ndef g():\n    RETURN 2\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentBlock {
+    line: usize,
+}
+
+impl IndentBlock {
+    /// Create a new [`IndentBlock`], for the block following `line` (1-based).
+    #[must_use]
+    pub fn new(line: usize) -> Self {
+        Self { line }
+    }
+
+    /// The byte range of the block in `input`, if any.
+    fn block(self, input: &str) -> Option<Range<usize>> {
+        let mut lines = input.split_inclusive('\n').scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        });
+
+        let (_, reference) = lines.nth(self.line.checked_sub(1)?)?;
+        if is_blank(reference) {
+            return None;
+        }
+        let depth = indentation(reference);
+
+        let mut block: Option<Range<usize>> = None;
+        for (start, line) in lines {
+            if is_blank(line) {
+                // Only part of the block if followed by more of it.
+                continue;
+            }
+
+            if indentation(line) <= depth {
+                break;
+            }
+
+            let end = start + line.len();
+            block = Some(block.map_or(start..end, |block| block.start..end));
+        }
+
+        block
+    }
+}
+
+impl Scoper for IndentBlock {
+    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
+        let ranges = self.block(input).into_iter().collect();
+
+        trace!("Ranges in scope for {:?}: {:?}", self, ranges);
+
+        ROScopes::from_raw_ranges(input, ranges)
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// The width of the leading indentation of `line`, in characters.
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use crate::scoping::view::ScopedViewBuilder;
+
+    use super::*;
+
+    const INPUT: &str = r"class A:
+    def f(self):
+        x = 1
+
+        return x
+
+    def g(self):
+        pass
+
+
+def h():
+	return 3
+";
+
+    #[rstest]
+    // Base cases
+    #[case("", 1, "")]
+    #[case("a", 1, "a")]
+    #[case("a\n b", 1, "a\n__")]
+    #[case("a\n b\n", 1, "a\n___")]
+    #[case("a\nb", 1, "a\nb")]
+    //
+    // Out of bounds, or blank reference lines
+    #[case("a\n b", 0, "a\n b")]
+    #[case("a\n b", 3, "a\n b")]
+    #[case("\n b", 1, "\n b")]
+    //
+    // Shallower lines end the block
+    #[case("  a\n b\n   c", 1, "  a\n b\n   c")]
+    #[case(" a\n  b\n c\n  d", 1, " a\n____ c\n  d")]
+    //
+    // Trailing blank lines are not part of it
+    #[case("a\n b\n\n  \nc", 1, "a\n___\n  \nc")]
+    #[case("a\n b\n\n", 1, "a\n___\n")]
+    //
+    // Line endings are kept in scope
+    #[case("a\r\n b\r\n", 1, "a\r\n____")]
+    fn test_indent_block(#[case] input: &str, #[case] line: usize, #[case] expected: &str) {
+        let mut builder = ScopedViewBuilder::new(input);
+        builder.explode(&IndentBlock::new(line));
+        let mut view = builder.build();
+        view.map(&|s: &str| "_".repeat(s.chars().count()));
+
+        assert_eq!(view.to_string(), expected);
+    }
+
+    #[rstest]
+    // Entire class, including inner blank lines, but not trailing ones
+    #[case(1, Some(9..89))]
+    // Method, including inner blank line
+    #[case(2, Some(26..58))]
+    #[case(3, None)]
+    #[case(7, Some(76..89))]
+    // Tab-indented
+    #[case(11, Some(100..110))]
+    #[case(12, None)]
+    #[case(13, None)]
+    fn test_indent_block_ranges(#[case] line: usize, #[case] expected: Option<Range<usize>>) {
+        assert_eq!(IndentBlock::new(line).block(INPUT), expected);
+    }
+}
@@ -0,0 +1,38 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Assembly",
+    Asm,
+    AsmQuery,
+    CustomAsmQuery,
+    PremadeAsmQuery,
+    tree_sitter_asm::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Assembly.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeAsmQuery {
+    /// Comments (`;`, `#` and `//` line comments, `/* */` block comments; comment chars
+    /// incl.).
+    ///
+    /// Dialects differ in their comment markers (`;` for NASM and ARM, `#` or `//` for
+    /// GNU `as`). As files do not declare their dialect, all markers are recognized.
+    Comments,
+    /// Strings (quotes incl.), such as arguments of `.ascii`/`db` directives.
+    Strings,
+}
+
+impl From<PremadeAsmQuery> for TSQuery {
+    fn from(value: PremadeAsmQuery) -> Self {
+        TSQuery::new(
+            &Asm::lang(),
+            match value {
+                PremadeAsmQuery::Comments => "[(line_comment) (block_comment)] @comment",
+                PremadeAsmQuery::Strings => "(string) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
@@ -0,0 +1,37 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Clojure",
+    Clojure,
+    ClojureQuery,
+    CustomClojureQuery,
+    PremadeClojureQuery,
+    tree_sitter_clojure_orchard::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Clojure.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeClojureQuery {
+    /// Comments (`;` line comments and `#_` form comments; comment chars incl.).
+    ///
+    /// A `#_` form comment comments out the entire form following it, which is part
+    /// of the comment.
+    Comments,
+    /// Strings (literals only, excluding regular expressions; quotes incl.).
+    Strings,
+}
+
+impl From<PremadeClojureQuery> for TSQuery {
+    fn from(value: PremadeClojureQuery) -> Self {
+        TSQuery::new(
+            &Clojure::lang(),
+            match value {
+                PremadeClojureQuery::Comments => "[(comment) (dis_expr)] @comment",
+                PremadeClojureQuery::Strings => "(str_lit) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
@@ -0,0 +1,92 @@
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
+use clap::ValueEnum;
+use const_format::concatcp;
+use std::fmt::Debug;
+
+language!(
+    "Elixir",
+    Elixir,
+    ElixirQuery,
+    CustomElixirQuery,
+    PremadeElixirQuery,
+    tree_sitter_elixir::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Elixir.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeElixirQuery {
+    /// Comments.
+    Comments,
+    /// Strings (regular, charlists, `~s`/`~S` sigils; interpolation is respected;
+    /// quotes included).
+    Strings,
+    /// Documentation (`@moduledoc`, `@doc`, `@typedoc` attribute strings).
+    Docs,
+}
+
+impl From<PremadeElixirQuery> for TSQuery {
+    fn from(value: PremadeElixirQuery) -> Self {
+        TSQuery::new(
+            &Elixir::lang(),
+            match value {
+                PremadeElixirQuery::Comments => "(comment) @comment",
+                PremadeElixirQuery::Strings => {
+                    // Sigils other than `~s`/`~S` are regexes, dates etc., so skip those.
+                    concatcp!(
+                        r#"
+                    [
+                        (string)
+                        (charlist)
+                    ]
+                    @string
+
+                    (
+                        (sigil (sigil_name) @name) @string
+                        (#match? @name "^[sS]$")
+                    )
+
+                    [
+                        (string (interpolation) @"#,
+                        IGNORE,
+                        r")
+                        (charlist (interpolation) @",
+                        IGNORE,
+                        r")
+                        (sigil (interpolation) @",
+                        IGNORE,
+                        r")
+                    ]
+                    "
+                    )
+                }
+                PremadeElixirQuery::Docs => {
+                    // The attribute name is only needed for matching, so is ignored.
+                    concatcp!(
+                        r#"
+                    (unary_operator
+                        operator: "@"
+                        operand: (call
+                            target: (identifier) @"#,
+                        IGNORE,
+                        r"
+                            (arguments
+                                [
+                                    (string)
+                                    (charlist)
+                                    (sigil)
+                                ] @doc
+                            )
+                        )
+                        (#match? @",
+                        IGNORE,
+                        r#" "^(module|type)?doc$")
+                    )
+                    "#
+                    )
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
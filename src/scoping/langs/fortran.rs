@@ -0,0 +1,37 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Fortran",
+    Fortran,
+    FortranQuery,
+    CustomFortranQuery,
+    PremadeFortranQuery,
+    tree_sitter_fortran::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Fortran.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeFortranQuery {
+    /// Comments (`!`; comment chars incl.).
+    ///
+    /// Only free-form source is supported: in fixed-form source, a `C` or `*` in the
+    /// first column also starts a comment, which is not recognized as such.
+    Comments,
+    /// Strings (single- and double-quoted; quotes incl.).
+    Strings,
+}
+
+impl From<PremadeFortranQuery> for TSQuery {
+    fn from(value: PremadeFortranQuery) -> Self {
+        TSQuery::new(
+            &Fortran::lang(),
+            match value {
+                PremadeFortranQuery::Comments => "(comment) @comment",
+                PremadeFortranQuery::Strings => "(string_literal) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
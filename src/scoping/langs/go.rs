@@ -1,14 +1,17 @@
-use super::{CodeQuery, Language, LanguageScoper, TSLanguage, TSQuery};
-use crate::scoping::{langs::IGNORE, ROScopes, Scoper};
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
 use clap::ValueEnum;
 use const_format::concatcp;
-use std::{fmt::Debug, str::FromStr};
-use tree_sitter::QueryError;
+use std::fmt::Debug;
 
-/// The Go language.
-pub type Go = Language<GoQuery>;
-/// A query for Go.
-pub type GoQuery = CodeQuery<CustomGoQuery, PremadeGoQuery>;
+language!(
+    "Go",
+    Go,
+    GoQuery,
+    CustomGoQuery,
+    PremadeGoQuery,
+    tree_sitter_go::LANGUAGE
+);
 
 /// Premade tree-sitter queries for Go.
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -26,7 +29,7 @@ pub enum PremadeGoQuery {
 impl From<PremadeGoQuery> for TSQuery {
     fn from(value: PremadeGoQuery) -> Self {
         TSQuery::new(
-            Go::lang(),
+            &Go::lang(),
             match value {
                 PremadeGoQuery::Comments => "(comment) @comment",
                 PremadeGoQuery::Strings => {
@@ -54,41 +57,3 @@ impl From<PremadeGoQuery> for TSQuery {
         .expect("Premade queries to be valid")
     }
 }
-
-/// A custom tree-sitter query for Go.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CustomGoQuery(String);
-
-impl FromStr for CustomGoQuery {
-    type Err = QueryError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TSQuery::new(Go::lang(), s) {
-            Ok(_) => Ok(Self(s.to_string())),
-            Err(e) => Err(e),
-        }
-    }
-}
-
-impl From<CustomGoQuery> for TSQuery {
-    fn from(value: CustomGoQuery) -> Self {
-        TSQuery::new(Go::lang(), &value.0)
-            .expect("Valid query, as object cannot be constructed otherwise")
-    }
-}
-
-impl Scoper for Go {
-    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
-        ROScopes::from_raw_ranges(input, Self::scope_via_query(&mut self.query(), input))
-    }
-}
-
-impl LanguageScoper for Go {
-    fn lang() -> TSLanguage {
-        tree_sitter_go::language()
-    }
-
-    fn query(&self) -> TSQuery {
-        self.query.clone().into()
-    }
-}
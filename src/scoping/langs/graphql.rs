@@ -0,0 +1,38 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "GraphQL",
+    Graphql,
+    GraphqlQuery,
+    CustomGraphqlQuery,
+    PremadeGraphqlQuery,
+    tree_sitter_graphql::LANGUAGE
+);
+
+/// Premade tree-sitter queries for GraphQL.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeGraphqlQuery {
+    /// Comments (`#`).
+    Comments,
+    /// Strings, regular and block strings (`"""`; quotes included). Includes
+    /// descriptions.
+    Strings,
+    /// Descriptions: the strings documenting types, fields, arguments etc. they precede.
+    Descriptions,
+}
+
+impl From<PremadeGraphqlQuery> for TSQuery {
+    fn from(value: PremadeGraphqlQuery) -> Self {
+        TSQuery::new(
+            &Graphql::lang(),
+            match value {
+                PremadeGraphqlQuery::Comments => "(comment) @comment",
+                PremadeGraphqlQuery::Strings => "(string_value) @string",
+                PremadeGraphqlQuery::Descriptions => "(description) @description",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
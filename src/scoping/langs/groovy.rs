@@ -0,0 +1,38 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Groovy",
+    Groovy,
+    GroovyQuery,
+    CustomGroovyQuery,
+    PremadeGroovyQuery,
+    tree_sitter_groovy::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Groovy.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeGroovyQuery {
+    /// Comments (line, block and doc comments; comment chars incl.).
+    Comments,
+    /// Strings (single-, double- and triple-quoted; quotes incl.). The grammar does not
+    /// parse interpolations (`$name`, `${...}`), so these are included.
+    Strings,
+}
+
+impl From<PremadeGroovyQuery> for TSQuery {
+    fn from(value: PremadeGroovyQuery) -> Self {
+        TSQuery::new(
+            &Groovy::lang(),
+            match value {
+                PremadeGroovyQuery::Comments => "[(line_comment) (block_comment)] @comment",
+                PremadeGroovyQuery::Strings => {
+                    // Single-quoted strings parse as character literals.
+                    "[(string_literal) (character_literal)] @string"
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
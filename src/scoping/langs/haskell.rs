@@ -0,0 +1,34 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Haskell",
+    Haskell,
+    HaskellQuery,
+    CustomHaskellQuery,
+    PremadeHaskellQuery,
+    tree_sitter_haskell::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Haskell.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeHaskellQuery {
+    /// Comments (line and block, including nested block comments).
+    Comments,
+    /// Strings (literal, quotes included).
+    Strings,
+}
+
+impl From<PremadeHaskellQuery> for TSQuery {
+    fn from(value: PremadeHaskellQuery) -> Self {
+        TSQuery::new(
+            &Haskell::lang(),
+            match value {
+                PremadeHaskellQuery::Comments => "(comment) @comment",
+                PremadeHaskellQuery::Strings => "(string) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
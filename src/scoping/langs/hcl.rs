@@ -0,0 +1,58 @@
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
+use clap::ValueEnum;
+use const_format::concatcp;
+use std::fmt::Debug;
+
+language!(
+    "HCL",
+    Hcl,
+    HclQuery,
+    CustomHclQuery,
+    PremadeHclQuery,
+    tree_sitter_hcl::LANGUAGE
+);
+
+/// Premade tree-sitter queries for HCL.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeHclQuery {
+    /// Comments (`#`, `//` and `/* */`).
+    Comments,
+    /// Strings, quoted and heredocs (`<<EOF`); delimiters included. Interpolations
+    /// (`${...}`) are excluded.
+    Strings,
+}
+
+impl From<PremadeHclQuery> for TSQuery {
+    fn from(value: PremadeHclQuery) -> Self {
+        TSQuery::new(
+            &Hcl::lang(),
+            match value {
+                PremadeHclQuery::Comments => "(comment) @comment",
+                PremadeHclQuery::Strings => {
+                    // Strings with interpolations are templates, not literals.
+                    concatcp!(
+                        r"
+                    [
+                        (string_lit)
+                        (quoted_template)
+                        (heredoc_template)
+                    ]
+                    @string
+
+                    [
+                        (quoted_template (template_interpolation) @",
+                        IGNORE,
+                        r")
+                        (heredoc_template (template_interpolation) @",
+                        IGNORE,
+                        r")
+                    ]
+                    "
+                    )
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
@@ -0,0 +1,34 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "INI",
+    Ini,
+    IniQuery,
+    CustomIniQuery,
+    PremadeIniQuery,
+    tree_sitter_ini::LANGUAGE
+);
+
+/// Premade tree-sitter queries for INI.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeIniQuery {
+    /// Comments (`;` and `#` styles; comment chars incl.).
+    Comments,
+    /// Values of settings (excluding section headers and keys).
+    Values,
+}
+
+impl From<PremadeIniQuery> for TSQuery {
+    fn from(value: PremadeIniQuery) -> Self {
+        TSQuery::new(
+            &Ini::lang(),
+            match value {
+                PremadeIniQuery::Comments => "(comment) @comment",
+                PremadeIniQuery::Values => "(setting_value) @value",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
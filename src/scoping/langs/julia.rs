@@ -0,0 +1,59 @@
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
+use clap::ValueEnum;
+use const_format::concatcp;
+use std::fmt::Debug;
+
+language!(
+    "Julia",
+    Julia,
+    JuliaQuery,
+    CustomJuliaQuery,
+    PremadeJuliaQuery,
+    tree_sitter_julia::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Julia.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeJuliaQuery {
+    /// Comments (line and, possibly nested, block comments).
+    Comments,
+    /// Strings (regular, triple-quoted, prefixed like `raw"..."`; interpolation is
+    /// respected; quotes included).
+    Strings,
+}
+
+impl From<PremadeJuliaQuery> for TSQuery {
+    fn from(value: PremadeJuliaQuery) -> Self {
+        TSQuery::new(
+            &Julia::lang(),
+            match value {
+                PremadeJuliaQuery::Comments => {
+                    r"
+                    [
+                        (line_comment)
+                        (block_comment)
+                    ]
+                    @comment
+                    "
+                }
+                PremadeJuliaQuery::Strings => {
+                    concatcp!(
+                        "
+                    [
+                        (string_literal)
+                        (prefixed_string_literal)
+                    ]
+                    @string
+
+                    (string_literal (string_interpolation) @",
+                        IGNORE,
+                        ")
+                    "
+                    )
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
@@ -0,0 +1,39 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Makefile",
+    Make,
+    MakeQuery,
+    CustomMakeQuery,
+    PremadeMakeQuery,
+    tree_sitter_make::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Makefile.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeMakeQuery {
+    /// Comments (`#`; comment chars incl.), at the top level and within rules.
+    ///
+    /// Tab-indented lines of recipes are passed to the shell verbatim, so `#` there
+    /// starts a shell comment, part of the recipe, not a Makefile comment. Comments
+    /// trailing variable assignments are parsed as part of the value, so are not
+    /// included either.
+    Comments,
+    /// Recipe lines of rules (the shell commands).
+    Recipes,
+}
+
+impl From<PremadeMakeQuery> for TSQuery {
+    fn from(value: PremadeMakeQuery) -> Self {
+        TSQuery::new(
+            &Make::lang(),
+            match value {
+                PremadeMakeQuery::Comments => "(comment) @comment",
+                PremadeMakeQuery::Recipes => "(recipe_line) @recipe",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
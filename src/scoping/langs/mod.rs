@@ -2,36 +2,232 @@ use super::Scoper;
 #[cfg(doc)]
 use crate::scoping::scope::Scope::{In, Out};
 use crate::scoping::scope::{merge, subtract};
+use itertools::Itertools;
 use log::{debug, trace};
-use std::{ops::Range, str::FromStr};
+use std::{error::Error, fmt, fmt::Write, ops::Range, str::FromStr};
+use streaming_iterator::StreamingIterator;
 pub use tree_sitter::{
-    Language as TSLanguage, Parser as TSParser, Query as TSQuery, QueryCursor as TSQueryCursor,
+    Language as TSLanguage, Node as TSNode, Parser as TSParser, Query as TSQuery,
+    QueryCursor as TSQueryCursor, Tree as TSTree,
 };
 
+/// Defines the language `$lang` of the given display `$name`, parsed by the given
+/// tree-sitter grammar.
+///
+/// Generates the language and its query type, a custom query type (validated on
+/// construction) and the [`Scoper`] and [`LanguageScoper`] implementations. The
+/// premade query type `$premade` is defined by the caller, and has to contain a
+/// `Comments` variant.
+macro_rules! language {
+    ($name:literal, $lang:ident, $query:ident, $custom:ident, $premade:ident, $grammar:expr $(,)?) => {
+        #[doc = concat!("The ", $name, " language.")]
+        pub type $lang = $crate::scoping::langs::Language<$query>;
+        #[doc = concat!("A query for ", $name, ".")]
+        pub type $query = $crate::scoping::langs::CodeQuery<$custom, $premade>;
+
+        #[doc = concat!("A custom tree-sitter query for ", $name, ".")]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $custom(String);
+
+        impl ::std::str::FromStr for $custom {
+            type Err = ::tree_sitter::QueryError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                use $crate::scoping::langs::LanguageScoper;
+
+                match $crate::scoping::langs::TSQuery::new(&$lang::lang(), s) {
+                    Ok(_) => Ok(Self(s.to_string())),
+                    Err(e) => Err(e),
+                }
+            }
+        }
+
+        impl From<$custom> for $crate::scoping::langs::TSQuery {
+            fn from(value: $custom) -> Self {
+                use $crate::scoping::langs::LanguageScoper;
+
+                Self::new(&$lang::lang(), &value.0)
+                    .expect("Valid query, as object cannot be constructed otherwise")
+            }
+        }
+
+        impl $crate::scoping::Scoper for $lang {
+            fn scope<'viewee>(
+                &self,
+                input: &'viewee str,
+            ) -> $crate::scoping::scope::ROScopes<'viewee> {
+                use $crate::scoping::langs::LanguageScoper;
+
+                $crate::scoping::scope::ROScopes::from_raw_ranges(
+                    input,
+                    Self::scope_via_query(&mut self.query(), input),
+                )
+            }
+        }
+
+        impl $crate::scoping::langs::LanguageScoper for $lang {
+            fn lang() -> $crate::scoping::langs::TSLanguage {
+                $grammar.into()
+            }
+
+            fn query(&self) -> $crate::scoping::langs::TSQuery {
+                self.ts_query()
+            }
+
+            fn comments_query() -> Self {
+                Self::new($query::Premade($premade::Comments))
+            }
+        }
+    };
+}
+
+/// Assembly.
+pub mod asm;
+/// Clojure.
+pub mod clojure;
 /// C#.
 pub mod csharp;
+/// Elixir.
+pub mod elixir;
+/// Fortran.
+pub mod fortran;
 /// Go.
 pub mod go;
+/// GraphQL.
+pub mod graphql;
+/// Groovy.
+pub mod groovy;
+/// Haskell.
+pub mod haskell;
+/// HCL.
+pub mod hcl;
+/// INI.
+pub mod ini;
+/// Julia.
+pub mod julia;
+/// Makefile.
+pub mod make;
+/// OCaml.
+pub mod ocaml;
+/// Perl.
+pub mod perl;
+/// Protobuf.
+pub mod proto;
 /// Python.
 pub mod python;
+/// R.
+pub mod r;
 /// Rust.
 pub mod rust;
+/// Scala.
+pub mod scala;
+/// Single-file components (Vue, Svelte).
+pub mod sfc;
+/// TSX.
+pub mod tsx;
 /// TypeScript.
 pub mod typescript;
+/// Zig.
+pub mod zig;
 
 /// Represents a (programming) language.
 #[derive(Debug)]
 pub struct Language<Q> {
     query: Q,
+    capture: Option<String>,
 }
 
 impl<Q> Language<Q> {
     /// Create a new language with the given associated query over it.
     pub fn new(query: Q) -> Self {
-        Self { query }
+        Self {
+            query,
+            capture: None,
+        }
+    }
+}
+
+impl<Q> Language<Q>
+where
+    Q: Clone + Into<TSQuery>,
+{
+    /// Only scope to the capture of the given `name`, instead of all captures of the
+    /// query.
+    ///
+    /// Useful for queries with multiple captures, e.g. `@key` and `@value`, where only
+    /// one of them is of interest. Captures named [`IGNORE`] keep their effect.
+    ///
+    /// # Errors
+    ///
+    /// If the query has no capture of the given `name`.
+    pub fn with_capture(mut self, name: &str) -> Result<Self, CaptureError> {
+        let query: TSQuery = self.query.clone().into();
+
+        if !query.capture_names().contains(&name) {
+            return Err(CaptureError::NoSuchCapture {
+                name: name.to_owned(),
+                available: query
+                    .capture_names()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            });
+        }
+
+        self.capture = Some(name.to_owned());
+        Ok(self)
+    }
+
+    /// The tree-sitter query, with all but the [selected
+    /// capture](Language::with_capture) disabled.
+    fn ts_query(&self) -> TSQuery {
+        let mut query: TSQuery = self.query.clone().into();
+
+        if let Some(capture) = &self.capture {
+            let capture_names = query
+                .capture_names()
+                .iter()
+                .map(ToString::to_string)
+                .collect_vec();
+            for name in capture_names {
+                if name != *capture && name != IGNORE {
+                    query.disable_capture(&name);
+                }
+            }
+        }
+
+        query
+    }
+}
+
+/// An error that can occur when selecting a capture of a query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureError {
+    /// The query has no capture of the given name.
+    NoSuchCapture {
+        /// The requested capture name.
+        name: String,
+        /// The capture names the query does have.
+        available: Vec<String>,
+    },
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchCapture { name, available } => {
+                write!(
+                    f,
+                    "Query has no capture named '{name}' (available: {})",
+                    available.join(", ")
+                )
+            }
+        }
     }
 }
 
+impl Error for CaptureError {}
+
 /// A query over a language, for scoping.
 ///
 /// Parts hit by the query are [`In`] scope, parts not hit are [`Out`] of scope.
@@ -63,6 +259,56 @@ where
     }
 }
 
+/// A scoper for comments of the language associated with the given file `extension`
+/// (without leading dot), or [`None`] if no supported language uses it.
+///
+/// ## Example
+///
+/// ```rust
+/// use srgn::scoping::{langs::comments_for_extension, view::ScopedViewBuilder};
+///
+/// let scoper = comments_for_extension("py").unwrap();
+/// let mut builder = ScopedViewBuilder::new("x = 1  # set x\n");
+/// builder.explode(&scoper);
+/// let mut view = builder.build();
+/// view.upper();
+///
+/// assert_eq!(view.to_string(), "x = 1  # SET X\n");
+/// assert!(comments_for_extension("txt").is_none());
+/// ```
+#[must_use]
+pub fn comments_for_extension(extension: &str) -> Option<Box<dyn Scoper>> {
+    let scoper: Box<dyn Scoper> = match extension {
+        "asm" | "s" | "S" => Box::new(asm::Asm::comments_query()),
+        "clj" | "cljs" | "cljc" | "edn" => Box::new(clojure::Clojure::comments_query()),
+        "cs" => Box::new(csharp::CSharp::comments_query()),
+        "ex" | "exs" => Box::new(elixir::Elixir::comments_query()),
+        "f90" | "f95" | "f03" | "f08" => Box::new(fortran::Fortran::comments_query()),
+        "go" => Box::new(go::Go::comments_query()),
+        "graphql" | "gql" => Box::new(graphql::Graphql::comments_query()),
+        "groovy" | "gradle" | "gvy" => Box::new(groovy::Groovy::comments_query()),
+        "hs" => Box::new(haskell::Haskell::comments_query()),
+        "hcl" | "tf" | "tfvars" => Box::new(hcl::Hcl::comments_query()),
+        "ini" => Box::new(ini::Ini::comments_query()),
+        "jl" => Box::new(julia::Julia::comments_query()),
+        "mk" | "mak" => Box::new(make::Make::comments_query()),
+        "ml" => Box::new(ocaml::Ocaml::comments_query()),
+        "pl" | "pm" => Box::new(perl::Perl::comments_query()),
+        "proto" => Box::new(proto::Proto::comments_query()),
+        "py" | "pyi" => Box::new(python::Python::comments_query()),
+        "r" | "R" => Box::new(r::R::comments_query()),
+        "rs" => Box::new(rust::Rust::comments_query()),
+        "scala" | "sc" => Box::new(scala::Scala::comments_query()),
+        "vue" | "svelte" => Box::new(sfc::SingleFileComponent::comments_query()),
+        "tsx" => Box::new(tsx::Tsx::comments_query()),
+        "ts" | "mts" | "cts" => Box::new(typescript::TypeScript::comments_query()),
+        "zig" => Box::new(zig::Zig::comments_query()),
+        _ => return None,
+    };
+
+    Some(scoper)
+}
+
 /// In a query, use this name to mark a capture to be ignored.
 ///
 /// Useful for queries where tree-sitter doesn't natively support a fitting node type,
@@ -79,29 +325,57 @@ pub trait LanguageScoper: Scoper {
     /// The language's tree-sitter query.
     fn query(&self) -> TSQuery;
 
+    /// This language, scoped to its comments via the respective premade query.
+    ///
+    /// Allows scoping comments without knowing the language up front, see also
+    /// [`comments_for_extension`].
+    #[must_use]
+    fn comments_query() -> Self
+    where
+        Self: Sized;
+
     /// The language's tree-sitter parser.
     #[must_use]
     fn parser() -> TSParser {
         let mut parser = TSParser::new();
         parser
-            .set_language(Self::lang())
+            .set_language(&Self::lang())
             .expect("Should be able to load language grammar and parser");
 
         parser
     }
 
-    /// Scope the given input using the language's query.
-    ///
-    /// In principle, this is the same as [`Scoper::scope`].
-    fn scope_via_query(query: &mut TSQuery, input: &str) -> Vec<Range<usize>> {
+    /// Parse the given input into the language's syntax tree.
+    #[must_use]
+    fn parse(input: &str) -> TSTree {
         // tree-sitter is about incremental parsing, which we don't use here
         let old_tree = None;
 
         trace!("Parsing into AST: {:?}", input);
 
-        let tree = Self::parser()
+        Self::parser()
             .parse(input, old_tree)
-            .expect("No language set in parser, or other unrecoverable error");
+            .expect("No language set in parser, or other unrecoverable error")
+    }
+
+    /// Render the syntax tree of the given input, for debugging queries.
+    ///
+    /// The result is an indented s-expression of named nodes, each with its field name
+    /// (if any) and byte range, e.g. `(program [0..6]`.
+    #[must_use]
+    fn parse_tree(input: &str) -> String {
+        let tree = Self::parse(input);
+
+        let mut out = String::new();
+        write_node(&mut out, tree.root_node(), None, 0);
+        out
+    }
+
+    /// Scope the given input using the language's query.
+    ///
+    /// In principle, this is the same as [`Scoper::scope`].
+    fn scope_via_query(query: &mut TSQuery, input: &str) -> Vec<Range<usize>> {
+        let tree = Self::parse(input);
 
         let root = tree.root_node();
         debug!(
@@ -112,14 +386,12 @@ pub trait LanguageScoper: Scoper {
         let run = |query: &mut TSQuery| {
             trace!("Running query: {:?}", query);
 
+            let mut res = Vec::new();
             let mut qc = TSQueryCursor::new();
-            let matches = qc.matches(query, root, input.as_bytes());
-
-            let ranges = matches
-                .flat_map(|query_match| query_match.captures)
-                .map(|capture| capture.node.byte_range());
-
-            let res = ranges.collect();
+            let mut matches = qc.matches(query, root, input.as_bytes());
+            while let Some(query_match) = matches.next() {
+                res.extend(query_match.captures.iter().map(|c| c.node.byte_range()));
+            }
             trace!("Querying yielded ranges: {:?}", res);
 
             // Merge, because tree-sitter queries with multiple captures will return
@@ -131,7 +403,7 @@ pub trait LanguageScoper: Scoper {
 
         let ranges = run(query);
 
-        let has_ignore = query.capture_names().iter().any(|name| name == IGNORE);
+        let has_ignore = query.capture_names().contains(&IGNORE);
 
         if has_ignore {
             let ignored_ranges = {
@@ -151,8 +423,45 @@ pub trait LanguageScoper: Scoper {
     }
 }
 
+/// Write `node` and all its named descendants as an indented s-expression.
+fn write_node(out: &mut String, node: TSNode, field: Option<&str>, depth: usize) {
+    if depth > 0 {
+        out.push('\n');
+    }
+    out.push_str(&"  ".repeat(depth));
+
+    if let Some(field) = field {
+        out.push_str(field);
+        out.push_str(": ");
+    }
+
+    let range = node.byte_range();
+    write!(out, "({} [{}..{}]", node.kind(), range.start, range.end)
+        .expect("writing to string cannot fail");
+
+    let mut cursor = node.walk();
+    if cursor.goto_first_child() {
+        loop {
+            let child = cursor.node();
+            if child.is_named() {
+                write_node(out, child, cursor.field_name(), depth + 1);
+            }
+
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    out.push(')');
+}
+
 fn disable_all_captures_except(capture_name: &str, query: &mut TSQuery) {
-    let capture_names = query.capture_names().to_owned();
+    let capture_names = query
+        .capture_names()
+        .iter()
+        .map(ToString::to_string)
+        .collect_vec();
     for name in capture_names {
         if name != capture_name {
             query.disable_capture(&name);
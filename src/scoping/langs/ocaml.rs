@@ -0,0 +1,34 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "OCaml",
+    Ocaml,
+    OcamlQuery,
+    CustomOcamlQuery,
+    PremadeOcamlQuery,
+    tree_sitter_ocaml::LANGUAGE_OCAML
+);
+
+/// Premade tree-sitter queries for OCaml.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeOcamlQuery {
+    /// Comments (including nested ones).
+    Comments,
+    /// Strings (regular and quoted `{| |}` strings; quotes included).
+    Strings,
+}
+
+impl From<PremadeOcamlQuery> for TSQuery {
+    fn from(value: PremadeOcamlQuery) -> Self {
+        TSQuery::new(
+            &Ocaml::lang(),
+            match value {
+                PremadeOcamlQuery::Comments => "(comment) @comment",
+                PremadeOcamlQuery::Strings => "[(string) (quoted_string)] @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
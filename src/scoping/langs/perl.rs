@@ -0,0 +1,46 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Perl",
+    Perl,
+    PerlQuery,
+    CustomPerlQuery,
+    PremadePerlQuery,
+    tree_sitter_perl::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Perl.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadePerlQuery {
+    /// Comments (regular `#` and POD blocks, `=pod` through `=cut`).
+    Comments,
+    /// Strings (quoted and `q`/`qq` operators, delimiters included; heredoc bodies,
+    /// terminators included).
+    Strings,
+}
+
+impl From<PremadePerlQuery> for TSQuery {
+    fn from(value: PremadePerlQuery) -> Self {
+        TSQuery::new(
+            &Perl::lang(),
+            match value {
+                PremadePerlQuery::Comments => "[(comments) (pod_statement)] @comment",
+                PremadePerlQuery::Strings => {
+                    r"
+                    [
+                        (string_single_quoted)
+                        (string_double_quoted)
+                        (string_q_quoted)
+                        (string_qq_quoted)
+                        (heredoc_body_statement)
+                    ]
+                    @string
+                    "
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
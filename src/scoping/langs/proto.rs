@@ -0,0 +1,34 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Protobuf",
+    Proto,
+    ProtoQuery,
+    CustomProtoQuery,
+    PremadeProtoQuery,
+    tree_sitter_proto::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Protobuf.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeProtoQuery {
+    /// Comments (line and block comments).
+    Comments,
+    /// Strings (option values, import paths, ...; quotes included).
+    Strings,
+}
+
+impl From<PremadeProtoQuery> for TSQuery {
+    fn from(value: PremadeProtoQuery) -> Self {
+        TSQuery::new(
+            &Proto::lang(),
+            match value {
+                PremadeProtoQuery::Comments => "(comment) @comment",
+                PremadeProtoQuery::Strings => "(string) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
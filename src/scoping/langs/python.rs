@@ -1,14 +1,17 @@
-use super::{CodeQuery, Language, LanguageScoper, TSLanguage, TSQuery};
-use crate::scoping::{langs::IGNORE, ROScopes, Scoper};
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
 use clap::ValueEnum;
 use const_format::concatcp;
-use std::{fmt::Debug, str::FromStr};
-use tree_sitter::QueryError;
+use std::fmt::Debug;
 
-/// The Python language.
-pub type Python = Language<PythonQuery>;
-/// A query for Python.
-pub type PythonQuery = CodeQuery<CustomPythonQuery, PremadePythonQuery>;
+language!(
+    "Python",
+    Python,
+    PythonQuery,
+    CustomPythonQuery,
+    PremadePythonQuery,
+    tree_sitter_python::LANGUAGE
+);
 
 /// Premade tree-sitter queries for Python.
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -30,7 +33,7 @@ pub enum PremadePythonQuery {
 impl From<PremadePythonQuery> for TSQuery {
     fn from(value: PremadePythonQuery) -> Self {
         TSQuery::new(
-            Python::lang(),
+            &Python::lang(),
             match value {
                 PremadePythonQuery::Comments => "(comment) @comment",
                 PremadePythonQuery::Strings => {
@@ -95,41 +98,3 @@ impl From<PremadePythonQuery> for TSQuery {
         .expect("Premade queries to be valid")
     }
 }
-
-/// A custom tree-sitter query for Python.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CustomPythonQuery(String);
-
-impl FromStr for CustomPythonQuery {
-    type Err = QueryError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TSQuery::new(Python::lang(), s) {
-            Ok(_) => Ok(Self(s.to_string())),
-            Err(e) => Err(e),
-        }
-    }
-}
-
-impl From<CustomPythonQuery> for TSQuery {
-    fn from(value: CustomPythonQuery) -> Self {
-        TSQuery::new(Python::lang(), &value.0)
-            .expect("Valid query, as object cannot be constructed otherwise")
-    }
-}
-
-impl Scoper for Python {
-    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
-        ROScopes::from_raw_ranges(input, Self::scope_via_query(&mut self.query(), input))
-    }
-}
-
-impl LanguageScoper for Python {
-    fn lang() -> TSLanguage {
-        tree_sitter_python::language()
-    }
-
-    fn query(&self) -> TSQuery {
-        self.query.clone().into()
-    }
-}
@@ -0,0 +1,51 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "R",
+    R,
+    RQuery,
+    CustomRQuery,
+    PremadeRQuery,
+    tree_sitter_r::LANGUAGE
+);
+
+/// Premade tree-sitter queries for R.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeRQuery {
+    /// Comments (excluding roxygen doc comments; comment chars incl.).
+    Comments,
+    /// Roxygen doc comments (`#'`; comment chars incl.).
+    DocComments,
+    /// Strings (single- and double-quoted; quotes included).
+    Strings,
+}
+
+impl From<PremadeRQuery> for TSQuery {
+    fn from(value: PremadeRQuery) -> Self {
+        TSQuery::new(
+            &R::lang(),
+            match value {
+                PremadeRQuery::Comments => {
+                    r#"
+                    (
+                        (comment) @comment
+                        (#not-match? @comment "^#'")
+                    )
+                    "#
+                }
+                PremadeRQuery::DocComments => {
+                    r#"
+                    (
+                        (comment) @comment
+                        (#match? @comment "^#'")
+                    )
+                    "#
+                }
+                PremadeRQuery::Strings => "(string) @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
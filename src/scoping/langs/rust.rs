@@ -1,13 +1,15 @@
-use super::{CodeQuery, Language, LanguageScoper, TSLanguage, TSQuery};
-use crate::scoping::{ROScopes, Scoper};
+use super::{LanguageScoper, TSQuery};
 use clap::ValueEnum;
-use std::{fmt::Debug, str::FromStr};
-use tree_sitter::QueryError;
+use std::fmt::Debug;
 
-/// The Rust language.
-pub type Rust = Language<RustQuery>;
-/// A query for Rust.
-pub type RustQuery = CodeQuery<CustomRustQuery, PremadeRustQuery>;
+language!(
+    "Rust",
+    Rust,
+    RustQuery,
+    CustomRustQuery,
+    PremadeRustQuery,
+    tree_sitter_rust::LANGUAGE
+);
 
 /// Premade tree-sitter queries for Rust.
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -28,7 +30,7 @@ pub enum PremadeRustQuery {
 impl From<PremadeRustQuery> for TSQuery {
     fn from(value: PremadeRustQuery) -> Self {
         TSQuery::new(
-            Rust::lang(),
+            &Rust::lang(),
             match value {
                 PremadeRustQuery::Comments => {
                     r#"
@@ -77,41 +79,3 @@ impl From<PremadeRustQuery> for TSQuery {
         .expect("Premade queries to be valid")
     }
 }
-
-/// A custom tree-sitter query for Rust.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CustomRustQuery(String);
-
-impl FromStr for CustomRustQuery {
-    type Err = QueryError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TSQuery::new(Rust::lang(), s) {
-            Ok(_) => Ok(Self(s.to_string())),
-            Err(e) => Err(e),
-        }
-    }
-}
-
-impl From<CustomRustQuery> for TSQuery {
-    fn from(value: CustomRustQuery) -> Self {
-        TSQuery::new(Rust::lang(), &value.0)
-            .expect("Valid query, as object cannot be constructed otherwise")
-    }
-}
-
-impl Scoper for Rust {
-    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
-        ROScopes::from_raw_ranges(input, Self::scope_via_query(&mut self.query(), input))
-    }
-}
-
-impl LanguageScoper for Rust {
-    fn lang() -> TSLanguage {
-        tree_sitter_rust::language()
-    }
-
-    fn query(&self) -> TSQuery {
-        self.query.clone().into()
-    }
-}
@@ -0,0 +1,51 @@
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
+use clap::ValueEnum;
+use const_format::concatcp;
+use std::fmt::Debug;
+
+language!(
+    "Scala",
+    Scala,
+    ScalaQuery,
+    CustomScalaQuery,
+    PremadeScalaQuery,
+    tree_sitter_scala::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Scala.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeScalaQuery {
+    /// Comments (line, block and Scaladoc).
+    Comments,
+    /// Strings (regular, triple-quoted and interpolated; interpolation is respected;
+    /// quotes included).
+    Strings,
+}
+
+impl From<PremadeScalaQuery> for TSQuery {
+    fn from(value: PremadeScalaQuery) -> Self {
+        TSQuery::new(
+            &Scala::lang(),
+            match value {
+                PremadeScalaQuery::Comments => "[(comment) (block_comment)] @comment",
+                PremadeScalaQuery::Strings => {
+                    concatcp!(
+                        r"
+                    [
+                        (string)
+                        (interpolated_string)
+                    ]
+                    @string
+
+                    (interpolated_string (interpolation) @",
+                        IGNORE,
+                        r")
+                    "
+                    )
+                }
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
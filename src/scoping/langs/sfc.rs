@@ -0,0 +1,155 @@
+use super::{
+    typescript::{PremadeTypeScriptQuery, TypeScript},
+    LanguageScoper, TSLanguage, TSParser, TSQuery, TSQueryCursor, TSTree,
+};
+use crate::scoping::{scope::merge, ROScopes, Scoper};
+use clap::ValueEnum;
+use log::{debug, trace};
+use std::ops::Range;
+use streaming_iterator::StreamingIterator;
+
+/// Premade queries for single-file components.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeSfcQuery {
+    /// Comments, in the markup (`<template>`), `<script>` and `<style>` alike.
+    Comments,
+    /// Strings: literals in `<script>` and `<style>`, attribute values in the markup
+    /// (quotes included).
+    Strings,
+}
+
+/// A single-file component, as used by Vue (`.vue`) and Svelte (`.svelte`).
+///
+/// Such files mix several languages. The markup is treated as HTML, `<script>` blocks
+/// as TypeScript (a superset of JavaScript) and `<style>` blocks as CSS. Each part is
+/// scoped by its own language, and the results are combined.
+///
+/// ## Example: comments of all sections
+///
+/// ```rust
+/// use srgn::scoping::{
+///     langs::sfc::{PremadeSfcQuery, SingleFileComponent},
+///     view::ScopedViewBuilder,
+/// };
+///
+/// let input = r#"<template><!-- a --><p>a</p></template>
+/// <script>/* a */ let a = 1;</script>
+/// <style>/* a */ a { color: red; }</style>
+/// "#;
+///
+/// let sfc = SingleFileComponent::new(PremadeSfcQuery::Comments);
+/// let mut builder = ScopedViewBuilder::new(input);
+/// builder.explode(&sfc);
+/// let mut view = builder.build();
+/// view.upper();
+///
+/// assert_eq!(
+///     view.to_string(),
+///     r#"<template><!-- A --><p>a</p></template>
+/// <script>/* A */ let a = 1;</script>
+/// <style>/* A */ a { color: red; }</style>
+/// "#
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SingleFileComponent {
+    query: PremadeSfcQuery,
+}
+
+impl SingleFileComponent {
+    /// Create a new [`SingleFileComponent`] scoper, using the given premade query.
+    #[must_use]
+    pub fn new(query: PremadeSfcQuery) -> Self {
+        Self { query }
+    }
+
+    /// Scoped to comments, see also [`LanguageScoper::comments_query`].
+    #[must_use]
+    pub fn comments_query() -> Self {
+        Self::new(PremadeSfcQuery::Comments)
+    }
+
+    fn markup_query(self) -> &'static str {
+        match self.query {
+            PremadeSfcQuery::Comments => "(comment) @comment",
+            PremadeSfcQuery::Strings => "(quoted_attribute_value) @string",
+        }
+    }
+
+    fn script_query(self) -> PremadeTypeScriptQuery {
+        match self.query {
+            PremadeSfcQuery::Comments => PremadeTypeScriptQuery::Comments,
+            PremadeSfcQuery::Strings => PremadeTypeScriptQuery::Strings,
+        }
+    }
+
+    fn style_query(self) -> &'static str {
+        match self.query {
+            PremadeSfcQuery::Comments => "(comment) @comment",
+            PremadeSfcQuery::Strings => "(string_value) @string",
+        }
+    }
+}
+
+impl Scoper for SingleFileComponent {
+    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
+        let html: TSLanguage = tree_sitter_html::LANGUAGE.into();
+        let tree = parse(&html, input);
+
+        // Contents of `<script>` and `<style>` are opaque to HTML, so `raw_text`.
+        let mut ranges = run(&html, self.markup_query(), &tree, input);
+
+        for script in run(&html, "(script_element (raw_text) @script)", &tree, input) {
+            trace!("Scoping script block at {:?}", script);
+
+            let mut query = TSQuery::from(self.script_query());
+            let found = TypeScript::scope_via_query(&mut query, &input[script.clone()]);
+            ranges.extend(offset(found, script.start));
+        }
+
+        let css: TSLanguage = tree_sitter_css::LANGUAGE.into();
+        for style in run(&html, "(style_element (raw_text) @style)", &tree, input) {
+            trace!("Scoping style block at {:?}", style);
+
+            let source = &input[style.clone()];
+            let found = run(&css, self.style_query(), &parse(&css, source), source);
+            ranges.extend(offset(found, style.start));
+        }
+
+        let ranges = merge(ranges);
+        debug!("Single-file component ranges: {:?}", ranges);
+
+        ROScopes::from_raw_ranges(input, ranges)
+    }
+}
+
+fn parse(lang: &TSLanguage, input: &str) -> TSTree {
+    let mut parser = TSParser::new();
+    parser
+        .set_language(lang)
+        .expect("Should be able to load language grammar and parser");
+
+    parser
+        .parse(input, None)
+        .expect("No language set in parser, or other unrecoverable error")
+}
+
+/// Run the (premade, hence valid) `query` over `tree`, returning all captured ranges.
+fn run(lang: &TSLanguage, query: &str, tree: &TSTree, input: &str) -> Vec<Range<usize>> {
+    let query = TSQuery::new(lang, query).expect("Premade queries to be valid");
+
+    let mut ranges = Vec::new();
+    let mut qc = TSQueryCursor::new();
+    let mut matches = qc.matches(&query, tree.root_node(), input.as_bytes());
+    while let Some(query_match) = matches.next() {
+        ranges.extend(query_match.captures.iter().map(|c| c.node.byte_range()));
+    }
+
+    ranges
+}
+
+fn offset(ranges: Vec<Range<usize>>, by: usize) -> impl Iterator<Item = Range<usize>> {
+    ranges
+        .into_iter()
+        .map(move |r| (r.start + by)..(r.end + by))
+}
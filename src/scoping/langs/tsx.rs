@@ -0,0 +1,65 @@
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
+use clap::ValueEnum;
+use const_format::concatcp;
+use std::fmt::Debug;
+
+language!(
+    "TSX",
+    Tsx,
+    TsxQuery,
+    CustomTsxQuery,
+    PremadeTsxQuery,
+    tree_sitter_typescript::LANGUAGE_TSX
+);
+
+/// Premade tree-sitter queries for TSX.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeTsxQuery {
+    /// Comments.
+    Comments,
+    /// Strings (literals, template strings), including JSX attribute values.
+    Strings,
+    /// JSX attributes, with their name and value (`className="a"`, `onClick={f}`),
+    /// and spread attributes (`{...props}`).
+    JsxAttributes,
+    /// JSX expressions embedded in markup (`{count}`, `{ok && <p />}`), braces
+    /// included. Expressions of attributes are not included, see
+    /// [`PremadeTsxQuery::JsxAttributes`] for those.
+    JsxExpressions,
+}
+
+impl From<PremadeTsxQuery> for TSQuery {
+    fn from(value: PremadeTsxQuery) -> Self {
+        TSQuery::new(
+            &Tsx::lang(),
+            match value {
+                PremadeTsxQuery::Comments => "(comment) @comment",
+                PremadeTsxQuery::Strings => {
+                    concatcp!(
+                        "
+                    [
+                        (string)
+                        (template_string (template_substitution) @",
+                        IGNORE,
+                        ")
+                    ]
+                    @string"
+                    )
+                }
+                PremadeTsxQuery::JsxAttributes => {
+                    r"
+                    (jsx_attribute) @attr
+
+                    [
+                        (jsx_opening_element (jsx_expression (spread_element)) @attr)
+                        (jsx_self_closing_element (jsx_expression (spread_element)) @attr)
+                    ]
+                    "
+                }
+                PremadeTsxQuery::JsxExpressions => "(jsx_element (jsx_expression) @jsxexpr)",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
@@ -1,14 +1,19 @@
-use super::{CodeQuery, Language, LanguageScoper, TSLanguage, TSQuery};
-use crate::scoping::{langs::IGNORE, ROScopes, Scoper};
+use super::{LanguageScoper, TSQuery};
+use crate::scoping::langs::IGNORE;
 use clap::ValueEnum;
 use const_format::concatcp;
-use std::{fmt::Debug, str::FromStr};
-use tree_sitter::QueryError;
+use itertools::Itertools;
+use std::{error::Error, fmt, fmt::Debug, str::FromStr};
+
+language!(
+    "TypeScript",
+    TypeScript,
+    TypeScriptQuery,
+    CustomTypeScriptQuery,
+    PremadeTypeScriptQuery,
+    tree_sitter_typescript::LANGUAGE_TYPESCRIPT
+);
 
-/// The TypeScript language.
-pub type TypeScript = Language<TypeScriptQuery>;
-/// A query for TypeScript.
-pub type TypeScriptQuery = CodeQuery<CustomTypeScriptQuery, PremadeTypeScriptQuery>;
 /// Premade tree-sitter queries for TypeScript.
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum PremadeTypeScriptQuery {
@@ -18,12 +23,90 @@ pub enum PremadeTypeScriptQuery {
     Strings,
     /// Imports (module specifiers).
     Imports,
+    /// Namespace blocks (`namespace`, `module`; including body).
+    Namespaces,
+    /// `throw` statements.
+    Throws,
+    /// `try` statements (including `catch` and `finally` blocks).
+    TryBlocks,
+    /// Array and tuple types (`T[]`, `[A, B]`).
+    ArrayTypes,
+    /// Spread (`...x`) and rest elements.
+    SpreadElements,
+    /// Expressions using optional chaining (`a?.b`, `a?.[0]`, `f?.()`).
+    OptionalChains,
+    /// Non-null assertions (`x!`).
+    NonNullAssertions,
+    /// `break` and `continue` statements (including labels).
+    BreakContinue,
+    /// Labeled statements (the label along with the labeled statement).
+    Labels,
+    /// Augmented (compound) assignments, such as `+=`, `??=` and `&&=`.
+    AugmentedAssignments,
+    /// Getters (`get` accessors of classes and object literals).
+    Getters,
+    /// Setters (`set` accessors of classes and object literals).
+    Setters,
+    /// Ternary (conditional) expressions. For nested ones, the outermost covers the
+    /// entire chain.
+    Ternaries,
+    /// Type assertions (`as`, including `as const`, angle-bracket casts) and `satisfies`.
+    TypeAssertions,
+    /// Default exports (`export default ...`).
+    DefaultExports,
+    /// Named exports: everything exported other than via `export default` (or `export =`),
+    /// such as `export { ... }`, exported declarations and re-exports (`export * from ...`).
+    NamedExports,
+    /// Constructors of classes, including any parameter properties
+    /// (`constructor(private x: T)`).
+    Constructors,
+    /// Imported names: default imports, named ones (including any alias, as in `b as c`)
+    /// and namespace imports (`* as ns`, only the name).
+    ImportSpecifiers,
+    /// Comments containing any of the [default tags][DEFAULT_COMMENT_TAGS] (`TODO`,
+    /// `FIXME`, ...) as a whole word. For other tags, see
+    /// [`CustomTypeScriptQuery::tagged_comments`].
+    TaggedComments,
+    /// Top-level statements (direct children of the module, such as declarations and
+    /// imports), one scope each. Comments are excluded.
+    TopLevelStatements,
+    /// Private fields (`#x`): their declarations (including any initializer), and all
+    /// references. For private methods, only their names.
+    PrivateFields,
+    /// Loops (`for`, `for...in`/`for...of`, `while` and `do...while`), including their
+    /// bodies.
+    Loops,
+    /// Destructuring patterns (object and array patterns), including nested and
+    /// defaulted bindings.
+    DestructuringPatterns,
+    /// Generator functions (`function*`), both declarations and expressions. Generator
+    /// methods are not included.
+    Generators,
+    /// Yield expressions (`yield` and `yield*`), including their operands.
+    YieldExpressions,
+    /// Module sources of imports and re-exports (`from "..."`), including quotes.
+    /// Unlike [`PremadeTypeScriptQuery::Imports`], also covers `export ... from`.
+    ImportSources,
+    /// Expression statements (such as calls and assignments), at any level.
+    ExpressionStatements,
+    /// Declarations (variables, functions, classes, interfaces, type aliases, enums and
+    /// ambient ones), at any level. Nested declarations are scoped along with their
+    /// parent.
+    Declarations,
+    /// Methods of object literals, written in shorthand (`m() {}`), including getters
+    /// and setters. Function-valued properties (`m: function () {}`) and class methods
+    /// are not included.
+    ObjectMethods,
 }
 
+/// Tags marking comments for [`PremadeTypeScriptQuery::TaggedComments`].
+pub const DEFAULT_COMMENT_TAGS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
 impl From<PremadeTypeScriptQuery> for TSQuery {
+    #[allow(clippy::too_many_lines)] // One arm per premade query; splitting it up gains nothing.
     fn from(value: PremadeTypeScriptQuery) -> Self {
         TSQuery::new(
-            TypeScript::lang(),
+            &TypeScript::lang(),
             match value {
                 PremadeTypeScriptQuery::Comments => "(comment) @comment",
                 PremadeTypeScriptQuery::Imports => {
@@ -41,48 +124,207 @@ impl From<PremadeTypeScriptQuery> for TSQuery {
                     @string"
                     )
                 }
+                PremadeTypeScriptQuery::Namespaces => {
+                    r"
+                    [
+                        (internal_module)
+                        (module)
+                    ]
+                    @namespace
+                    "
+                }
+                PremadeTypeScriptQuery::Throws => "(throw_statement) @throw",
+                PremadeTypeScriptQuery::TryBlocks => "(try_statement) @try",
+                PremadeTypeScriptQuery::ArrayTypes => "[(array_type) (tuple_type)] @arraytype",
+                PremadeTypeScriptQuery::SpreadElements => {
+                    "[(spread_element) (rest_pattern)] @spread"
+                }
+                PremadeTypeScriptQuery::OptionalChains => {
+                    r#"
+                    [
+                        (member_expression (optional_chain))
+                        (subscript_expression (optional_chain))
+                        (call_expression "?.")
+                    ]
+                    @optional
+                    "#
+                }
+                PremadeTypeScriptQuery::NonNullAssertions => "(non_null_expression) @nonnull",
+                PremadeTypeScriptQuery::BreakContinue => {
+                    "[(break_statement) (continue_statement)] @jump"
+                }
+                PremadeTypeScriptQuery::Labels => "(labeled_statement) @label",
+                PremadeTypeScriptQuery::AugmentedAssignments => {
+                    "(augmented_assignment_expression) @augassign"
+                }
+                PremadeTypeScriptQuery::Getters => r#"(method_definition "get") @getter"#,
+                PremadeTypeScriptQuery::Setters => r#"(method_definition "set") @setter"#,
+                PremadeTypeScriptQuery::Ternaries => "(ternary_expression) @ternary",
+                PremadeTypeScriptQuery::TypeAssertions => {
+                    "[(as_expression) (satisfies_expression) (type_assertion)] @assertion"
+                }
+                PremadeTypeScriptQuery::DefaultExports => r#"(export_statement "default") @export"#,
+                PremadeTypeScriptQuery::NamedExports => {
+                    r#"
+                    (
+                        (export_statement) @export
+                        (#not-match? @export "^export\\s+(default|=)")
+                    )
+                    "#
+                }
+                PremadeTypeScriptQuery::Constructors => {
+                    // The name is captured for the predicate only; it lies within the
+                    // constructor, so scope is unaffected.
+                    r#"
+                    (class_body
+                        (method_definition
+                            name: (property_identifier) @name
+                            (#eq? @name "constructor")
+                        ) @constructor
+                    )
+                    "#
+                }
+                PremadeTypeScriptQuery::ImportSpecifiers => {
+                    r"
+                    (import_clause
+                        [
+                            (identifier) @spec
+                            (named_imports (import_specifier) @spec)
+                            (namespace_import (identifier) @spec)
+                        ]
+                    )
+                    "
+                }
+                PremadeTypeScriptQuery::TaggedComments => {
+                    // Keep in sync with `DEFAULT_COMMENT_TAGS`.
+                    r#"
+                    (
+                        (comment) @comment
+                        (#match? @comment "(^|\\W)(TODO|FIXME|HACK|XXX)(\\W|$)")
+                    )
+                    "#
+                }
+                PremadeTypeScriptQuery::TopLevelStatements => {
+                    r#"
+                    (
+                        (program (_) @stmt)
+                        (#not-match? @stmt "^(//|/\\*)")
+                    )
+                    "#
+                }
+                PremadeTypeScriptQuery::PrivateFields => {
+                    r"
+                    [
+                        (public_field_definition name: (private_property_identifier))
+                        (private_property_identifier)
+                    ]
+                    @private
+                    "
+                }
+                PremadeTypeScriptQuery::Loops => {
+                    r"
+                    [
+                        (for_statement)
+                        (for_in_statement)
+                        (while_statement)
+                        (do_statement)
+                    ]
+                    @loop
+                    "
+                }
+                PremadeTypeScriptQuery::DestructuringPatterns => {
+                    "[(object_pattern) (array_pattern)] @pattern"
+                }
+                PremadeTypeScriptQuery::Generators => {
+                    "[(generator_function) (generator_function_declaration)] @generator"
+                }
+                PremadeTypeScriptQuery::YieldExpressions => "(yield_expression) @yield",
+                PremadeTypeScriptQuery::ImportSources => {
+                    r"
+                    [
+                        (import_statement source: (string) @source)
+                        (export_statement source: (string) @source)
+                    ]
+                    "
+                }
+                PremadeTypeScriptQuery::ExpressionStatements => "(expression_statement) @statement",
+                PremadeTypeScriptQuery::Declarations => {
+                    r"
+                    [
+                        (lexical_declaration)
+                        (variable_declaration)
+                        (function_declaration)
+                        (generator_function_declaration)
+                        (class_declaration)
+                        (abstract_class_declaration)
+                        (interface_declaration)
+                        (type_alias_declaration)
+                        (enum_declaration)
+                        (ambient_declaration)
+                    ]
+                    @declaration
+                    "
+                }
+                PremadeTypeScriptQuery::ObjectMethods => "(object (method_definition) @objmethod)",
             },
         )
         .expect("Premade queries to be valid")
     }
 }
 
-/// A custom tree-sitter query for TypeScript.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct CustomTypeScriptQuery(String);
+impl CustomTypeScriptQuery {
+    /// A query for comments containing any of the given `tags` as a whole word, such
+    /// as `TODO`. Tags are matched literally and case-sensitively.
+    ///
+    /// # Errors
+    ///
+    /// If `tags` is empty or contains an empty tag, as either would match all
+    /// comments.
+    ///
+    /// # Panics
+    ///
+    /// Never, as tags are escaped before building the query.
+    pub fn tagged_comments(tags: &[impl AsRef<str>]) -> Result<Self, TagsError> {
+        if tags.is_empty() {
+            return Err(TagsError::NoTags);
+        }
+        if tags.iter().any(|tag| tag.as_ref().is_empty()) {
+            return Err(TagsError::EmptyTag);
+        }
 
-impl FromStr for CustomTypeScriptQuery {
-    type Err = QueryError;
+        let alternatives = tags
+            .iter()
+            .map(|tag| fancy_regex::escape(tag.as_ref()))
+            .join("|");
+        // No `\b`, which wouldn't work for tags with non-word characters, like `C++`.
+        let pattern = format!(r"(^|\W)({alternatives})(\W|$)");
+        // Escape for embedding into a query string literal.
+        let pattern = pattern.replace('\\', r"\\").replace('"', r#"\""#);
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TSQuery::new(TypeScript::lang(), s) {
-            Ok(_) => Ok(Self(s.to_string())),
-            Err(e) => Err(e),
-        }
+        Ok(Self::from_str(&format!(
+            r#"((comment) @comment (#match? @comment "{pattern}"))"#
+        ))
+        .expect("Query from escaped tags to be valid"))
     }
 }
 
-impl From<CustomTypeScriptQuery> for TSQuery {
-    fn from(value: CustomTypeScriptQuery) -> Self {
-        TSQuery::new(TypeScript::lang(), &value.0)
-            .expect("Valid query, as object cannot be constructed otherwise")
-    }
+/// An error that can occur when building a query from comment tags, see
+/// [`CustomTypeScriptQuery::tagged_comments`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagsError {
+    /// No tags were given.
+    NoTags,
+    /// One of the tags is empty.
+    EmptyTag,
 }
 
-impl Scoper for TypeScript {
-    fn scope<'viewee>(&self, input: &'viewee str) -> ROScopes<'viewee> {
-        let ranges = Self::scope_via_query(&mut self.query(), input);
-
-        ROScopes::from_raw_ranges(input, ranges)
+impl fmt::Display for TagsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTags => write!(f, "At least one comment tag required"),
+            Self::EmptyTag => write!(f, "Comment tags cannot be empty"),
+        }
     }
 }
 
-impl LanguageScoper for TypeScript {
-    fn lang() -> TSLanguage {
-        tree_sitter_typescript::language_typescript()
-    }
-
-    fn query(&self) -> TSQuery {
-        self.query.clone().into()
-    }
-}
+impl Error for TagsError {}
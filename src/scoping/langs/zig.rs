@@ -0,0 +1,34 @@
+use super::{LanguageScoper, TSQuery};
+use clap::ValueEnum;
+use std::fmt::Debug;
+
+language!(
+    "Zig",
+    Zig,
+    ZigQuery,
+    CustomZigQuery,
+    PremadeZigQuery,
+    tree_sitter_zig::LANGUAGE
+);
+
+/// Premade tree-sitter queries for Zig.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum PremadeZigQuery {
+    /// Comments (regular `//`, doc `///` and top-level doc `//!`).
+    Comments,
+    /// Strings (regular and multiline `\\` strings; quotes included).
+    Strings,
+}
+
+impl From<PremadeZigQuery> for TSQuery {
+    fn from(value: PremadeZigQuery) -> Self {
+        TSQuery::new(
+            &Zig::lang(),
+            match value {
+                PremadeZigQuery::Comments => "(comment) @comment",
+                PremadeZigQuery::Strings => "[(string) (multiline_string)] @string",
+            },
+        )
+        .expect("Premade queries to be valid")
+    }
+}
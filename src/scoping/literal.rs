@@ -31,7 +31,7 @@ impl TryFrom<String> for Literal {
 
     fn try_from(literal: String) -> Result<Self, Self::Error> {
         let unescaped =
-            unescape(&literal).ok_or(LiteralError::InvalidEscapeSequences(literal.to_string()))?;
+            unescape(&literal).ok_or(LiteralError::InvalidEscapeSequences(literal.clone()))?;
 
         Ok(Self(unescaped))
     }
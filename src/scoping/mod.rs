@@ -6,6 +6,10 @@ use crate::scoping::{scope::Scope, view::ScopedView};
 
 /// Fixes for DOS-style line endings.
 pub mod dosfix;
+/// Exclude parts from scope.
+pub mod exclusion;
+/// Create scoped views using indentation.
+pub mod indent;
 /// Create scoped views using programming language grammar-aware types.
 pub mod langs;
 /// Create scoped views using string literals.
@@ -30,7 +30,7 @@ pub struct RWScope<'viewee>(pub Scope<'viewee, Cow<'viewee, str>>);
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RWScopes<'viewee>(pub Vec<RWScope<'viewee>>);
 
-impl<'viewee> ROScope<'viewee> {
+impl ROScope<'_> {
     /// Check whether the scope is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
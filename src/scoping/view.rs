@@ -1,4 +1,4 @@
-use crate::actions::{self, Action, ReplacementCreationError};
+use crate::actions::{self, Action, ActionError, ReplacementCreationError};
 use crate::scoping::dosfix::DosFix;
 use crate::scoping::scope::{
     ROScope, ROScopes, RWScope, RWScopes,
@@ -8,6 +8,7 @@ use crate::scoping::Scoper;
 use log::{debug, trace, warn};
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 
 /// A view of some input, sorted into parts, which are either [`In`] or [`Out`] of scope
 /// for processing.
@@ -63,6 +64,29 @@ impl<'viewee> ScopedView<'viewee> {
         self
     }
 
+    /// Like [`Self::map`], but applying the `action` via [`Action::act_checked`],
+    /// stopping at the first failure.
+    ///
+    /// # Errors
+    ///
+    /// If the `action` fails for any [`In`] scope item. Items processed up to that
+    /// point remain replaced.
+    pub fn try_map(&mut self, action: &impl Action) -> Result<&mut Self, ActionError> {
+        for scope in &mut self.scopes.0 {
+            if let RWScope(In(s)) = scope {
+                let res = action.act_checked(s)?;
+                debug!(
+                    "Replacing '{}' with '{}'",
+                    s.escape_debug(),
+                    res.escape_debug()
+                );
+                *scope = RWScope(In(Cow::Owned(res)));
+            }
+        }
+
+        Ok(self)
+    }
+
     /// Squeeze all consecutive [`In`] scopes into a single occurrence (the first one).
     pub fn squeeze(&mut self) -> &mut Self {
         debug!("Squeezing view by collapsing all consecutive in-scope occurrences.");
@@ -80,6 +104,12 @@ impl<'viewee> ScopedView<'viewee> {
         self
     }
 
+    /// Access the scopes contained in this view.
+    #[must_use]
+    pub fn scopes(&self) -> &RWScopes<'viewee> {
+        &self.scopes
+    }
+
     /// Check whether anything is [`In`] scope for this view.
     #[must_use]
     pub fn has_any_in_scope(&self) -> bool {
@@ -88,12 +118,116 @@ impl<'viewee> ScopedView<'viewee> {
             RWScope(Out(_)) => false,
         })
     }
+
+    /// Compute the minimal [`Edit`]s applying `action` would make to this view.
+    ///
+    /// The view itself is **not** modified. Byte ranges of the returned edits refer to
+    /// the view's current contents (its [`fmt::Display`] representation), which for a
+    /// freshly built view is the original input. Edits are sorted by position and do
+    /// not overlap. Only parts which actually change are reported, with any common
+    /// prefix and suffix between a scope and its replacement trimmed away.
+    ///
+    /// This is useful for editors holding text in data structures such as ropes, where
+    /// applying small edits is much cheaper than replacing the entire buffer.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use srgn::scoping::view::{Edit, ScopedViewBuilder};
+    ///
+    /// let view = ScopedViewBuilder::new("Hello").build();
+    /// let edits = view.edits(&|s: &str| s.replace('H', "J"));
+    ///
+    /// assert_eq!(
+    ///     edits,
+    ///     vec![Edit {
+    ///         byte_range: 0..1,
+    ///         new_text: "J".to_string()
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn edits(&self, action: &impl Action) -> Vec<Edit> {
+        let mut edits = Vec::new();
+        let mut offset = 0;
+
+        for scope in &self.scopes.0 {
+            let old: &str = scope.into();
+
+            if let RWScope(In(_)) = scope {
+                let new = action.act(old);
+
+                if let Some(edit) = Edit::minimal(offset, old, &new) {
+                    trace!("Found edit: {:?}", edit);
+                    edits.push(edit);
+                }
+            }
+
+            offset += old.len();
+        }
+
+        debug!("Computed edits: {:?}", edits);
+
+        edits
+    }
+}
+
+/// A single replacement of a byte range by some new text.
+///
+/// See [`ScopedView::edits`] for how these are produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    /// The byte range of the text to be replaced.
+    pub byte_range: Range<usize>,
+    /// The text to replace the range with.
+    pub new_text: String,
+}
+
+impl Edit {
+    /// Construct the smallest edit turning `old` into `new`, where `old` starts at byte
+    /// `offset`. Returns [`None`] if there is nothing to do.
+    #[must_use]
+    pub fn minimal(offset: usize, old: &str, new: &str) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+
+        let prefix: usize = old
+            .chars()
+            .zip(new.chars())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+
+        let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+
+        let suffix: usize = old_rest
+            .chars()
+            .rev()
+            .zip(new_rest.chars().rev())
+            .take_while(|(a, b)| a == b)
+            .map(|(a, _)| a.len_utf8())
+            .sum();
+
+        Some(Self {
+            byte_range: (offset + prefix)..(offset + old.len() - suffix),
+            new_text: new_rest[..new_rest.len() - suffix].to_string(),
+        })
+    }
 }
 
 /// Implementations of all available actions as dedicated methods.
 ///
 /// Where actions don't take arguments, neither do the methods.
-impl<'viewee> ScopedView<'viewee> {
+impl ScopedView<'_> {
+    /// Apply the [`actions::CollapseBlankLines`] action to this view (see
+    /// [`Self::map`]).
+    pub fn collapse_blank_lines(&mut self, max: usize) -> &mut Self {
+        let action = actions::CollapseBlankLines::new(max);
+
+        self.map(&action)
+    }
+
     /// Apply the default [`actions::Deletion`] action to this view (see [`Self::map`]).
     pub fn delete(&mut self) -> &mut Self {
         let action = actions::Deletion::default();
@@ -101,6 +235,20 @@ impl<'viewee> ScopedView<'viewee> {
         self.map(&action)
     }
 
+    /// Apply the [`actions::Escape`] action to this view (see [`Self::map`]).
+    pub fn escape(&mut self, mode: actions::EscapeMode) -> &mut Self {
+        let action = actions::Escape::new(mode);
+
+        self.map(&action)
+    }
+
+    /// Apply the [`actions::Expand`] action to this view (see [`Self::map`]).
+    pub fn expand(&mut self, width: usize) -> &mut Self {
+        let action = actions::Expand::new(width);
+
+        self.map(&action)
+    }
+
     /// Apply the default [`actions::German`] action to this view (see [`Self::map`]).
     #[cfg(feature = "german")]
     pub fn german(&mut self) -> &mut Self {
@@ -109,6 +257,13 @@ impl<'viewee> ScopedView<'viewee> {
         self.map(&action)
     }
 
+    /// Apply the [`actions::Indent`] action to this view (see [`Self::map`]).
+    pub fn indent(&mut self, unit: actions::IndentUnit, levels: isize) -> &mut Self {
+        let action = actions::Indent::new(unit, levels);
+
+        self.map(&action)
+    }
+
     /// Apply the default [`actions::Lower`] action to this view (see [`Self::map`]).
     pub fn lower(&mut self) -> &mut Self {
         let action = actions::Lower::default();
@@ -124,6 +279,21 @@ impl<'viewee> ScopedView<'viewee> {
         self.map(&action)
     }
 
+    /// Apply the [`actions::NumberLines`] action to this view (see [`Self::map`]).
+    pub fn number_lines(&mut self, start: usize, step: usize, format: &str) -> &mut Self {
+        let mut action = actions::NumberLines::default();
+        action.start(start).step(step).format(format);
+
+        self.map(&action)
+    }
+
+    /// Apply the [`actions::Redact`] action to this view (see [`Self::map`]).
+    pub fn redact(&mut self, mode: actions::RedactMode) -> &mut Self {
+        let action = actions::Redact::new(mode);
+
+        self.map(&action)
+    }
+
     /// Apply the [`actions::Replacement`] action to this view (see [`Self::map`]).
     ///
     /// ## Errors
@@ -136,6 +306,27 @@ impl<'viewee> ScopedView<'viewee> {
         Ok(self.map(&action))
     }
 
+    /// Apply the [`actions::ReverseLines`] action to this view (see [`Self::map`]).
+    pub fn reverse_lines(&mut self) -> &mut Self {
+        let action = actions::ReverseLines::default();
+
+        self.map(&action)
+    }
+
+    /// Apply the default [`actions::SortLines`] action to this view (see [`Self::map`]).
+    pub fn sort_lines(&mut self) -> &mut Self {
+        let action = actions::SortLines::default();
+
+        self.map(&action)
+    }
+
+    /// Apply the [`actions::Surround`] action to this view (see [`Self::map`]).
+    pub fn surround(&mut self, prefix: String, suffix: String) -> &mut Self {
+        let action = actions::Surround::new(prefix, suffix);
+
+        self.map(&action)
+    }
+
     /// Apply the [`actions::Symbols`] action to this view (see [`Self::map`]).
     #[cfg(feature = "symbols")]
     pub fn symbols(&mut self) -> &mut Self {
@@ -159,12 +350,46 @@ impl<'viewee> ScopedView<'viewee> {
         self.map(&action)
     }
 
+    /// Apply the [`actions::Transform`] action to this view (see [`Self::map`]).
+    pub fn transform(&mut self, transform: actions::Transform) -> &mut Self {
+        self.map(&transform)
+    }
+
+    /// Apply the default [`actions::TrimTrailing`] action to this view (see
+    /// [`Self::map`]).
+    pub fn trim_trailing(&mut self) -> &mut Self {
+        let action = actions::TrimTrailing::default();
+
+        self.map(&action)
+    }
+
+    /// Apply the [`actions::Unexpand`] action to this view (see [`Self::map`]).
+    pub fn unexpand(&mut self, width: usize) -> &mut Self {
+        let action = actions::Unexpand::new(width);
+
+        self.map(&action)
+    }
+
+    /// Apply the [`actions::Unescape`] action to this view (see [`Self::map`]).
+    pub fn unescape(&mut self, mode: actions::EscapeMode) -> &mut Self {
+        let action = actions::Unescape::new(mode);
+
+        self.map(&action)
+    }
+
     /// Apply the default [`actions::Upper`] action to this view (see [`Self::map`]).
     pub fn upper(&mut self) -> &mut Self {
         let action = actions::Upper::default();
 
         self.map(&action)
     }
+
+    /// Apply the [`actions::Wrap`] action to this view (see [`Self::map`]).
+    pub fn wrap(&mut self, width: usize) -> &mut Self {
+        let action = actions::Wrap::new(width);
+
+        self.map(&action)
+    }
 }
 
 impl fmt::Display for ScopedView<'_> {
@@ -209,13 +434,7 @@ impl<'viewee> ScopedViewBuilder<'viewee> {
         self.apply_dos_line_endings_fix();
 
         ScopedView {
-            scopes: RWScopes(
-                self.scopes
-                    .0
-                    .into_iter()
-                    .map(std::convert::Into::into)
-                    .collect(),
-            ),
+            scopes: RWScopes(self.scopes.0.into_iter().map(Into::into).collect()),
         }
     }
 
@@ -255,7 +474,7 @@ impl<'viewee> ScopedViewBuilder<'viewee> {
     /// Panics if the [`Scoper`] scopes such that the view is no longer consistent, i.e.
     /// gaps were created and the original input can no longer be reconstructed from the
     /// new view.
-    pub fn explode(&mut self, scoper: &impl Scoper) -> &mut Self {
+    pub fn explode(&mut self, scoper: &(impl Scoper + ?Sized)) -> &mut Self {
         trace!("Exploding scopes: {:?}", self.scopes);
         let mut new = Vec::with_capacity(self.scopes.0.len());
         for scope in self.scopes.0.drain(..) {
@@ -313,7 +532,7 @@ impl<'viewee> IntoIterator for ScopedViewBuilder<'viewee> {
 
 #[cfg(test)]
 mod tests {
-    use crate::scoping::view::ScopedViewBuilder;
+    use crate::scoping::view::{Edit, ScopedViewBuilder};
     use crate::RegexPattern;
     use rstest::rstest;
 
@@ -433,4 +652,72 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    // Nothing to do
+    #[case("", r"\w+", vec![])]
+    #[case("abc", r"\d+", vec![])]
+    #[case("ABC", r"\w+", vec![])]
+    //
+    // Simple cases
+    #[case("abc", r"\w+", vec![Edit { byte_range: 0..3, new_text: "ABC".to_string() }])]
+    #[case("a b", r"\w", vec![
+        Edit { byte_range: 0..1, new_text: "A".to_string() },
+        Edit { byte_range: 2..3, new_text: "B".to_string() },
+    ])]
+    //
+    // Common prefix and suffix are trimmed, but not common infixes
+    #[case("aBc", r"\w+", vec![Edit { byte_range: 0..3, new_text: "ABC".to_string() }])]
+    #[case("ABc", r"\w+", vec![Edit { byte_range: 2..3, new_text: "C".to_string() }])]
+    #[case("abC", r"\w+", vec![Edit { byte_range: 0..2, new_text: "AB".to_string() }])]
+    //
+    // Multi-byte characters
+    #[case("äöü", r"\w+", vec![Edit { byte_range: 0..6, new_text: "ÄÖÜ".to_string() }])]
+    #[case("Äöü", r"\w+", vec![Edit { byte_range: 2..6, new_text: "ÖÜ".to_string() }])]
+    #[case("你a好", r"\w+", vec![Edit { byte_range: 3..4, new_text: "A".to_string() }])]
+    fn test_edits(#[case] input: &str, #[case] pattern: RegexPattern, #[case] expected: Vec<Edit>) {
+        let mut builder = ScopedViewBuilder::new(input);
+        builder.explode(&crate::scoping::regex::Regex::new(pattern));
+        let view = builder.build();
+
+        let edits = view.edits(&crate::actions::Upper::default());
+
+        assert_eq!(edits, expected);
+    }
+
+    #[rstest]
+    #[case("Hello, world!", r"o")]
+    #[case("Hello, world!", r"\w+")]
+    #[case("Hello, world!", r"l+")]
+    #[case("Grüß Gott, 你好 🦀!", r"\S+")]
+    #[case("aaa", r"")]
+    fn test_edits_reconstruct_output(#[case] input: &str, #[case] pattern: RegexPattern) {
+        let action = |s: &str| format!("<{}>", s.to_uppercase());
+
+        let mut builder = ScopedViewBuilder::new(input);
+        builder.explode(&crate::scoping::regex::Regex::new(pattern));
+        let mut view = builder.build();
+
+        let edits = view.edits(&action);
+
+        // Apply in order, keeping track of how previous edits shifted positions.
+        let mut result = input.to_string();
+        let mut shift: isize = 0;
+        for Edit {
+            byte_range,
+            new_text,
+        } in edits
+        {
+            let start = byte_range.start.checked_add_signed(shift).unwrap();
+            let end = byte_range.end.checked_add_signed(shift).unwrap();
+
+            result.replace_range(start..end, &new_text);
+
+            shift += isize::try_from(new_text.len()).unwrap()
+                - isize::try_from(byte_range.len()).unwrap();
+        }
+
+        view.map(&action);
+        assert_eq!(result, view.to_string());
+    }
 }
@@ -41,6 +41,8 @@ Duebel
 
 "#,
         r#"Duebel -> 1.5mm; Wand != 3m²... UEBELTAETER! 😫"#,
+        // Loanwords validly spelled with "ss", never "ß"
+        r#"Business, Stress, Express und Adresse."#,
     ];
 
     #[derive(Debug, Serialize)]
@@ -55,7 +57,7 @@ Duebel
     fn test_cli_stdin(
         // This will generate all permutations of all `values`, which is a lot but
         // neatly manageable through `insta`.
-        #[values(1, 2, 3)] n_sample: usize,
+        #[values(1, 2, 3, 4)] n_sample: usize,
         #[values(
             &["--german"],
             &["--symbols"],
@@ -106,6 +108,16 @@ Duebel
 
     #[rstest]
     #[case("**/*.py", "tests/files-option/basic-python/in", ["foo", "baz"].as_slice())]
+    #[case(
+        "**/*.py",
+        "tests/files-option/basic-python/in",
+        ["--threads", "1", "foo", "baz"].as_slice()
+    )]
+    #[case(
+        "*.*",
+        "tests/files-option/polyglot-comments/in",
+        ["--comments", "--upper"].as_slice()
+    )]
     fn test_cli_files(#[case] glob: &str, #[case] left: PathBuf, #[case] add_args: &[&str]) {
         // Arrange
         let mut cmd = get_cmd();
@@ -148,6 +160,529 @@ Duebel
         cmd.assert().success();
     }
 
+    #[test]
+    fn test_cli_files_max_filesize() {
+        let dir = tempfile::tempdir().unwrap();
+        let small = dir.path().join("small.txt");
+        let large = dir.path().join("large.txt");
+        std::fs::write(&small, "hello\n").unwrap();
+        std::fs::write(&large, "hello\n".repeat(100)).unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.current_dir(dir.path());
+        cmd.args([
+            "--files",
+            "*.txt",
+            "--max-filesize",
+            "100",
+            "--upper",
+            "hello",
+        ]);
+
+        let output = cmd.output().expect("failed to execute binary under test");
+        assert!(output.status.success(), "Binary execution itself failed");
+
+        // Only the processed file is reported
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(stdout.lines().collect::<Vec<_>>(), ["small.txt"]);
+
+        assert_eq!(std::fs::read_to_string(&small).unwrap(), "HELLO\n");
+        assert_eq!(
+            std::fs::read_to_string(&large).unwrap(),
+            "hello\n".repeat(100),
+            "Oversized file should be left untouched"
+        );
+    }
+
+    #[test]
+    fn test_cli_print_tree() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--typescript", "comments", "--print-tree"])
+            .write_stdin("// Hello\nlet x = 1;\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.starts_with("(program [0.."), "Got: {stdout}");
+        assert!(stdout.contains("(comment [0..8])"), "Got: {stdout}");
+    }
+
+    #[test]
+    fn test_cli_print_tree_requires_language() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--print-tree"]).write_stdin("let x = 1;\n");
+
+        cmd.assert().failure();
+    }
+
+    #[test]
+    fn test_cli_stats() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--german", "--stats"])
+            .write_stdin("Gruess Gott, Strasse!\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(stdout, "Grüß Gott, Straße!\n");
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            stderr.contains("stats: scopes=1 changed=1 bytes_changed=17"),
+            "Unexpected stderr: {stderr}"
+        );
+    }
+
+    #[rstest]
+    #[case(&["hello"], "say \x1b[1;31mhello\x1b[0m, \x1b[1;31mhello\x1b[0m!\n")]
+    #[case(&["--upper", "hello"], "say \x1b[1;31mHELLO\x1b[0m, \x1b[1;31mHELLO\x1b[0m!\n")]
+    #[case(&["--upper", "hello,"], "say \x1b[1;31mHELLO\x1b[0m, hello!\n")]
+    fn test_cli_color(#[case] args: &[&str], #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--color", "always"])
+            .args(args)
+            .write_stdin("say hello, hello!\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert_eq!(stdout, expected);
+    }
+
+    #[test]
+    fn test_cli_color_never() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--color", "never", "--upper", "hello"])
+            .write_stdin("say hello\n");
+
+        cmd.assert().success().stdout("say HELLO\n");
+    }
+
+    #[test]
+    fn test_cli_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out.txt");
+
+        let mut cmd = get_cmd();
+        cmd.args(["--output", out.to_str().unwrap(), "--upper", "hello"])
+            .write_stdin("hello world\n");
+
+        cmd.assert().success().stdout("");
+        assert_eq!(std::fs::read_to_string(&out).unwrap(), "HELLO world\n");
+
+        // No leftover temporary files
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_cli_output_not_clobbered_on_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out.txt");
+        std::fs::write(&out, "original\n").unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.args(["--output", out.to_str().unwrap(), "--fail-none", r"\d"])
+            .write_stdin("no digits here\n");
+
+        cmd.assert().failure();
+        assert_eq!(std::fs::read_to_string(&out).unwrap(), "original\n");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_cli_output_dash_is_stdout() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--output", "-", "--upper", "hello"])
+            .write_stdin("hello world\n");
+
+        cmd.assert().success().stdout("HELLO world\n");
+    }
+
+    #[test]
+    fn test_cli_indent_block() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--indent-block", "1", "--upper", r"\w+"])
+            .write_stdin("def f():\n    x = 1\n\n    return x\n\ny = f()\n");
+
+        cmd.assert()
+            .success()
+            .stdout("def f():\n    X = 1\n\n    RETURN X\n\ny = f()\n");
+    }
+
+    #[test]
+    fn test_cli_german_word_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let word_list = dir.path().join("words.txt");
+        std::fs::write(&word_list, "Gott\nStraße\n").unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.args([
+            "--german",
+            "--german-word-list",
+            word_list.to_str().unwrap(),
+        ])
+        .write_stdin("Gruess Gott, Strasse!\n");
+
+        cmd.assert().success().stdout("Gruess Gott, Straße!\n");
+    }
+
+    #[test]
+    fn test_cli_german_word_list_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let word_list = dir.path().join("missing.txt");
+
+        let mut cmd = get_cmd();
+        cmd.args([
+            "--german",
+            "--german-word-list",
+            word_list.to_str().unwrap(),
+        ])
+        .write_stdin("Gruess Gott\n");
+
+        cmd.assert().failure();
+    }
+
+    #[rstest]
+    #[case(
+        &["--german"],
+        "\"Gruess Gott, Strasse!\" -> \"Grüß Gott, Straße!\"\n"
+    )]
+    #[case(
+        &["--german", r"\w+"],
+        "\"Gruess\" -> \"Grüß\"\n\"Strasse\" -> \"Straße\"\n"
+    )]
+    #[case(&["--german", "Gott"], "")]
+    #[case(
+        &["--color", "always", "--german", r"\w+"],
+        concat!(
+            "\x1b[1;31m\"Gruess\"\x1b[0m -> \x1b[1;31m\"Grüß\"\x1b[0m\n",
+            "\x1b[1;31m\"Strasse\"\x1b[0m -> \x1b[1;31m\"Straße\"\x1b[0m\n"
+        )
+    )]
+    #[case(
+        &["--color", "never", "--german", r"\w+"],
+        "\"Gruess\" -> \"Grüß\"\n\"Strasse\" -> \"Straße\"\n"
+    )]
+    fn test_cli_dry_run(#[case] args: &[&str], #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.arg("--dry-run")
+            .args(args)
+            .write_stdin("Gruess Gott, Strasse!\n");
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[rstest]
+    #[case(
+        &["--python", "comments"],
+        "# redacted",
+        r"(?s).+",
+        "x = 1  # secret\n# also secret\ny = 2\n",
+        "x = 1  # redacted\n# redacted\ny = 2\n"
+    )]
+    #[case(
+        &["--typescript", "comments"],
+        "/* redacted */",
+        r"(?s).+",
+        "let x = 1; // secret\n/**\n * also secret\n */\n",
+        "let x = 1; /* redacted */\n/* redacted */\n"
+    )]
+    #[case(&[], "_", r"\d", "a1 b2", "a_ b_")]
+    fn test_cli_replace_empty_with(
+        #[case] args: &[&str],
+        #[case] placeholder: &str,
+        #[case] scope: &str,
+        #[case] stdin: &str,
+        #[case] expected: &str,
+    ) {
+        let mut cmd = get_cmd();
+
+        cmd.args(args)
+            .args(["--delete", "--replace-empty-with", placeholder, scope])
+            .write_stdin(stdin);
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[test]
+    fn test_cli_replace_empty_with_requires_delete() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--replace-empty-with", "_", r"\d"])
+            .write_stdin("a1");
+
+        cmd.assert().failure();
+    }
+
+    #[rstest]
+    #[case("--no-newline", "hello\n", "HELLO")]
+    #[case("--no-newline", "hello", "HELLO")]
+    #[case("--no-newline", "hello\r\n", "HELLO")]
+    #[case("--no-newline", "hello\n\n", "HELLO\n")] // Only a single one
+    #[case("--ensure-newline", "hello\n", "HELLO\n")]
+    #[case("--ensure-newline", "hello", "HELLO\n")]
+    #[case("--ensure-newline", "", "\n")]
+    fn test_cli_newline(#[case] flag: &str, #[case] stdin: &str, #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args([flag, "--upper", r"\w+"]).write_stdin(stdin);
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[test]
+    fn test_cli_newline_flags_conflict() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--no-newline", "--ensure-newline", "--upper"])
+            .write_stdin("hello\n");
+
+        cmd.assert().failure();
+    }
+
+    #[test]
+    fn test_cli_dry_run_files_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, "Strasse\n").unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.current_dir(dir.path());
+        cmd.args(["--files", "*.txt", "--dry-run", "--german", r"\w+"]);
+
+        cmd.assert()
+            .success()
+            .stdout("a.txt: \"Strasse\" -> \"Straße\"\n");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "Strasse\n");
+    }
+
+    #[test]
+    fn test_cli_show_scopes() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.ts");
+        let contents = "// First\nlet x = 1; /* Second */\n";
+        std::fs::write(&file, contents).unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.current_dir(dir.path());
+        cmd.args([
+            "--files",
+            "*.ts",
+            "--show-scopes",
+            "--typescript",
+            "comments",
+        ]);
+        cmd.arg("--upper"); // Not applied
+
+        cmd.assert()
+            .success()
+            .stdout("a.ts: «// First»\na.ts: let x = 1; «/* Second */»\n");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), contents);
+    }
+
+    #[test]
+    fn test_cli_show_scopes_stdin() {
+        let mut cmd = get_cmd();
+        cmd.args(["--show-scopes", r"\d+"]).write_stdin("a1 b22\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), "a«1» b«22»\n");
+
+        // Showing scopes is an action of its own, so no complaints about missing ones.
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            !stderr.contains("No actions loaded"),
+            "Unexpected stderr: {stderr}"
+        );
+    }
+
+    #[rstest]
+    #[case("y\nn\na\n", "A b C\n")]
+    #[case("n\ny\nn\n", "a B c\n")]
+    #[case("a\n", "A B C\n")]
+    #[case("y\nq\n", "A b c\n")]
+    #[case("x\ny\nq\n", "A b c\n")] // Invalid answers are asked again
+    #[case("", "a b c\n")] // Running out of answers declines
+    fn test_cli_interactive(#[case] answers: &str, #[case] expected: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, "a b c\n").unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.current_dir(dir.path());
+        cmd.args(["--files", "*.txt", "--interactive", "--upper", r"\w"])
+            .write_stdin(answers);
+
+        cmd.assert().success();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(&["--escape", "json"], "say \"hi\"\nnow", r#"say \"hi\"\nnow"#)]
+    #[case(&["--unescape", "json"], r#"say \"hi\"\nnow"#, "say \"hi\"\nnow")]
+    #[case(&["--escape", "shell"], "cost: $5", r"cost: \$5")]
+    #[case(&["--escape", "regex"], "1+1=2?", r"1\+1=2\?")]
+    fn test_cli_escape(#[case] args: &[&str], #[case] stdin: &str, #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args(args).args(["(?s).*"]).write_stdin(stdin);
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[rstest]
+    #[case(&[], "a\n\nb\n\nc\n")]
+    #[case(&["--max-blank-lines", "2"], "a\n\n\nb\n\nc\n")]
+    #[case(&["--max-blank-lines", "0"], "a\nb\nc\n")]
+    fn test_cli_collapse_blank_lines(#[case] args: &[&str], #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--collapse-blank-lines"])
+            .args(args)
+            .args(["(?s).*"])
+            .write_stdin("a\n\n\n\nb\n\nc\n");
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[rstest]
+    #[case(&["--redact", "asterisks"], "token=******** user=alice\n")]
+    #[case(&["--redact", "hash"], "token=2cf24dba user=alice\n")]
+    #[case(&["--redact", "partial", "--redact-keep", "1"], "token=h***o user=alice\n")]
+    fn test_cli_redact(#[case] args: &[&str], #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args(args)
+            .arg("hello")
+            .write_stdin("token=hello user=alice\n");
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[rstest]
+    #[case(&[], "1 a\n2 b\n3 c\n")]
+    #[case(&["--number-start", "100"], "100 a\n101 b\n102 c\n")]
+    #[case(&["--number-step", "10", "--number-format", "{n}: "], "1: a\n11: b\n21: c\n")]
+    fn test_cli_number_lines(#[case] args: &[&str], #[case] expected: &str) {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--number-lines"])
+            .args(args)
+            .args(["(?s).*"])
+            .write_stdin("a\nb\nc\n");
+
+        cmd.assert().success().stdout(expected.to_owned());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cli_exec() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--exec", "tr a-z A-Z", r"\w+"])
+            .write_stdin("hello, world\n");
+
+        cmd.assert().success().stdout("HELLO, WORLD\n");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cli_exec_failure_aborts() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--exec", "echo oops >&2; exit 3", r"\w+"])
+            .write_stdin("hello\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("exit code 3: oops"), "Got: {stderr}");
+    }
+
+    #[test]
+    fn test_cli_transform_failure_aborts() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--transform", "base64-decode", r"\S+"])
+            .write_stdin("not-base64!\n");
+
+        let output = cmd.output().expect("failed to execute process");
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(
+            stderr.contains("Not valid base64: 'not-base64!'"),
+            "Got: {stderr}"
+        );
+    }
+
+    #[rstest]
+    #[case(&["--typescript-query-name", "exported_functions"])]
+    #[case(&["--ts-query-name", "exported_functions"])]
+    fn test_cli_config_named_query(#[case] args: &[&str]) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".srgn.toml"),
+            r#"
+[typescript.queries]
+exported_functions = "(export_statement (function_declaration name: (identifier) @name))"
+"#,
+        )
+        .unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.current_dir(dir.path());
+        cmd.args(args)
+            .args(["--upper"])
+            .write_stdin("export function f() {}\nfunction g() {}\n");
+
+        cmd.assert()
+            .success()
+            .stdout("export function F() {}\nfunction g() {}\n");
+    }
+
+    #[rstest]
+    #[case("[typescript.queries]\nother = \"(identifier) @i\"\n")] // Unknown name
+    #[case("[typescript.queries]\nexported_functions = \"(nonsense\"\n")] // Invalid query
+    #[case("[typescript]\nquerys = {}\n")] // Typo
+    fn test_cli_config_named_query_failures(#[case] config: &str) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, config).unwrap();
+
+        let mut cmd = get_cmd();
+        cmd.args(["--config", path.to_str().unwrap()])
+            .args(["--ts-query-name", "exported_functions"])
+            .write_stdin("export function f() {}\n");
+
+        cmd.assert().failure();
+    }
+
+    #[test]
+    fn test_cli_interactive_conflicts_with_dry_run() {
+        let mut cmd = get_cmd();
+
+        cmd.args(["--interactive", "--dry-run", "--upper", r"\w"])
+            .write_stdin("a");
+
+        cmd.assert().failure();
+    }
+
     fn get_cmd() -> Command {
         Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap()
     }
@@ -198,14 +733,11 @@ Duebel
                     .with_context(|| format!("Failure reading right file: {:?}", right))?;
 
                 if left_contents != right_contents {
-                    return Err(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!(
-                            "File contents differ: left: {:?}, right: {:?}",
-                            left.path(),
-                            right
-                        ),
-                    )
+                    return Err(std::io::Error::other(format!(
+                        "File contents differ: left: {:?}, right: {:?}",
+                        left.path(),
+                        right
+                    ))
                     .into());
                 }
             } else if metadata.is_dir() {
@@ -239,7 +771,7 @@ Duebel
             if entry.file_type()?.is_dir() {
                 copy_tree(&entry.path(), &dst.join(entry.file_name()))?;
             } else {
-                std::fs::copy(&entry.path(), &dst.join(entry.file_name()))?;
+                std::fs::copy(entry.path(), dst.join(entry.file_name()))?;
             }
         }
 
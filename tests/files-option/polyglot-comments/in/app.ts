@@ -0,0 +1,4 @@
+// greet someone
+function greet(name: string): string {
+    return "hello, " + name; /* inline note */
+}
@@ -0,0 +1,6 @@
+// add two numbers
+fn add(a: i32, b: i32) -> i32 {
+    a + b // simple enough
+}
+
+const URL: &str = "https://example.com"; // a url
@@ -0,0 +1,6 @@
+# compute things
+def square(x):
+    return x * x  # "no quotes needed"
+
+
+message = "# not a comment"
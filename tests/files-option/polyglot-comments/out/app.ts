@@ -0,0 +1,4 @@
+// GREET SOMEONE
+function greet(name: string): string {
+    return "hello, " + name; /* INLINE NOTE */
+}
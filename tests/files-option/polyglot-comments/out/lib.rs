@@ -0,0 +1,6 @@
+// ADD TWO NUMBERS
+fn add(a: i32, b: i32) -> i32 {
+    a + b // SIMPLE ENOUGH
+}
+
+const URL: &str = "https://example.com"; // A URL
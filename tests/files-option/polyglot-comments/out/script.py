@@ -0,0 +1,6 @@
+# COMPUTE THINGS
+def square(x):
+    return x * x  # "NO QUOTES NEEDED"
+
+
+message = "# not a comment"
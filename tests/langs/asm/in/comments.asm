@@ -0,0 +1,12 @@
+; Top-level comment__T__
+section .data__T__
+    msg__T__ db "Hello; not a comment__T__", 10
+
+section .text__T__
+global _start__T__
+
+_start__T__:
+    mov eax, 4      ; trailing comment__T__
+    mov ebx, 1      // another style__T__
+    /* block comment__T__ */
+    int 0x80
@@ -0,0 +1,9 @@
+; "not a string__T__"
+.data
+greeting__T__:
+    .ascii "Hello, World!__T__\n"
+farewell__T__:
+    .asciz "Bye; for now__T__"
+
+.text
+    mov x0, #1
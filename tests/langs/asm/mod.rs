@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::asm::{Asm, AsmQuery, PremadeAsmQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.asm", AsmQuery::Premade(PremadeAsmQuery::Comments))]
+#[case("strings.asm", AsmQuery::Premade(PremadeAsmQuery::Strings))]
+fn test_asm_nuke(#[case] file: &str, #[case] query: AsmQuery) {
+    let lang = Asm::new(query);
+
+    let (input, output) = get_input_output("asm", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
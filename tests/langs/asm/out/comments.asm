@@ -0,0 +1,12 @@
+; Top-level comment
+section .data__T__
+    msg__T__ db "Hello; not a comment__T__", 10
+
+section .text__T__
+global _start__T__
+
+_start__T__:
+    mov eax, 4      ; trailing comment
+    mov ebx, 1      // another style
+    /* block comment */
+    int 0x80
@@ -0,0 +1,9 @@
+; "not a string__T__"
+.data
+greeting__T__:
+    .ascii "Hello, World!\n"
+farewell__T__:
+    .asciz "Bye; for now"
+
+.text
+    mov x0, #1
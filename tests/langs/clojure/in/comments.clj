@@ -0,0 +1,18 @@
+; Top-level comment__T__
+(ns example.core__T__
+  (:require [clojure.string :as str__T__]))
+
+;; Docstrings are not comments__T__
+(defn greet__T__
+  "Greets someone.__T__"
+  [name__T__]
+  (str "Hello, " name__T__)) ; trailing comment__T__
+
+#_(defn unused__T__ [] "ignored__T__")
+
+(def config__T__
+  {:port 8080
+   #_:debug__T__ #_true__T__
+   :host "localhost__T__"})
+
+(println #_"discarded__T__" "kept__T__")
@@ -0,0 +1,11 @@
+;; A comment "not a string__T__"
+(ns example.strings__T__)
+
+(def greeting "Hello, World!__T__")
+(def multiline "first line__T__
+second line__T__")
+(def escaped "with \"quotes\"__T__")
+(def pattern #"regex__T__ \d+")
+(def ch \a)
+(def kw :keyword__T__)
+{:edn "value__T__" :n 42}
@@ -0,0 +1,18 @@
+; Top-level comment
+(ns example.core__T__
+  (:require [clojure.string :as str__T__]))
+
+;; Docstrings are not comments
+(defn greet__T__
+  "Greets someone.__T__"
+  [name__T__]
+  (str "Hello, " name__T__)) ; trailing comment
+
+#_(defn unused [] "ignored")
+
+(def config__T__
+  {:port 8080
+   #_:debug #_true
+   :host "localhost__T__"})
+
+(println #_"discarded" "kept__T__")
@@ -0,0 +1,11 @@
+;; A comment "not a string__T__"
+(ns example.strings__T__)
+
+(def greeting "Hello, World!")
+(def multiline "first line
+second line")
+(def escaped "with \"quotes\"")
+(def pattern #"regex__T__ \d+")
+(def ch \a)
+(def kw :keyword__T__)
+{:edn "value" :n 42}
@@ -0,0 +1,9 @@
+# A comment__T__
+defmodule Comments__T__ do
+  # Another comment__T__
+  def run(x__T__) do
+    x__T__ + 1 # Trailing__T__ comment
+  end
+
+  def string, do: "# Not a comment__T__"
+end
@@ -0,0 +1,16 @@
+defmodule Strings__T__ do
+  def examples(name__T__) do
+    plain = "plain__T__ string"
+    interpolated = "Hello, #{name__T__}!__T__"
+    charlist = 'char__T__list'
+    sigil = ~s(sigil__T__ string)
+    raw_sigil = ~S(raw sigil__T__ string)
+    heredoc = """
+    A heredoc__T__
+    """
+    regex = ~r/not__T__ a string/
+    date = ~D[2024-01-01]
+    atom__T__ = :"quoted atom"
+    {plain, interpolated, charlist, sigil, raw_sigil, heredoc, regex, date, atom__T__}
+  end
+end
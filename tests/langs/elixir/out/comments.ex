@@ -0,0 +1,9 @@
+# A comment
+defmodule Comments__T__ do
+  # Another comment
+  def run(x__T__) do
+    x__T__ + 1 # Trailing comment
+  end
+
+  def string, do: "# Not a comment__T__"
+end
@@ -0,0 +1,27 @@
+defmodule Greeter__T__ do
+  @moduledoc """
+  Greets people.
+
+  Nothing more, nothing less.
+  """
+
+  @typedoc "A name to greet."
+  @type name :: String.t()
+
+  @doc """
+  Says hello to `name`.
+  """
+  def hello(name) do
+    "Hello, #{name}!__T__"
+  end
+
+  @doc false
+  def secret__T__, do: :ok
+
+  @doc ~S"""
+  Sigil docs work too.
+  """
+  def sigil_doc, do: :ok
+
+  @other_attribute "Not a doc__T__"
+end
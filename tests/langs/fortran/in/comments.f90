@@ -0,0 +1,9 @@
+! Top-level comment__T__
+program hello__T__
+    implicit none
+    ! Indented comment__T__
+    integer :: count__T__ = 0 ! Trailing comment__T__
+    character(len=20) :: msg__T__ = "Not ! a comment__T__"
+
+    print *, msg__T__
+end program hello__T__
@@ -0,0 +1,9 @@
+! A comment 'not a string__T__'
+program strings__T__
+    implicit none
+    character(len=*), parameter :: a__T__ = 'single__T__'
+    character(len=*), parameter :: b__T__ = "double__T__"
+    character(len=*), parameter :: c__T__ = 'it''s escaped__T__'
+
+    print *, "Hello, World!__T__", a__T__
+end program strings__T__
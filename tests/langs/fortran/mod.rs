@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::fortran::{Fortran, FortranQuery, PremadeFortranQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.f90", FortranQuery::Premade(PremadeFortranQuery::Comments))]
+#[case("strings.f90", FortranQuery::Premade(PremadeFortranQuery::Strings))]
+fn test_fortran_nuke(#[case] file: &str, #[case] query: FortranQuery) {
+    let lang = Fortran::new(query);
+
+    let (input, output) = get_input_output("fortran", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
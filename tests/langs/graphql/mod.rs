@@ -0,0 +1,23 @@
+use rstest::rstest;
+use srgn::scoping::langs::graphql::{Graphql, GraphqlQuery, PremadeGraphqlQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case(
+    "comments.graphql",
+    GraphqlQuery::Premade(PremadeGraphqlQuery::Comments)
+)]
+#[case("strings.graphql", GraphqlQuery::Premade(PremadeGraphqlQuery::Strings))]
+#[case(
+    "descriptions.graphql",
+    GraphqlQuery::Premade(PremadeGraphqlQuery::Descriptions)
+)]
+fn test_graphql_nuke(#[case] file: &str, #[case] query: GraphqlQuery) {
+    let lang = Graphql::new(query);
+
+    let (input, output) = get_input_output("graphql", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
@@ -0,0 +1,12 @@
+// Line comment__T__
+/* Block comment__T__ */
+
+/**
+ * Groovydoc comment__T__
+ * @param name__T__ who to greet
+ */
+def greet__T__(String name__T__) {
+    println "Hello, $name__T__" // Trailing comment__T__
+}
+
+def url__T__ = "http://example.com__T__" /* not a comment: // in string */
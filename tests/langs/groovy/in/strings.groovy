@@ -0,0 +1,11 @@
+// A comment "not a string__T__"
+def name__T__ = 'single__T__'
+def greeting__T__ = "Hello, __T__${name__T__}!__T__"
+def short__T__ = "Dear $name__T__,__T__"
+def text__T__ = '''
+    Triple single__T__
+'''
+def gstring__T__ = """
+    Triple double__T__ for ${name__T__.toUpperCase()}
+"""
+def number__T__ = 42
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::groovy::{Groovy, GroovyQuery, PremadeGroovyQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.groovy", GroovyQuery::Premade(PremadeGroovyQuery::Comments))]
+#[case("strings.groovy", GroovyQuery::Premade(PremadeGroovyQuery::Strings))]
+fn test_groovy_nuke(#[case] file: &str, #[case] query: GroovyQuery) {
+    let lang = Groovy::new(query);
+
+    let (input, output) = get_input_output("groovy", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
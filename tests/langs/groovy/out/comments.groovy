@@ -0,0 +1,12 @@
+// Line comment
+/* Block comment */
+
+/**
+ * Groovydoc comment
+ * @param name who to greet
+ */
+def greet__T__(String name__T__) {
+    println "Hello, $name__T__" // Trailing comment
+}
+
+def url__T__ = "http://example.com__T__" /* not a comment: // in string */
@@ -0,0 +1,11 @@
+// A comment "not a string__T__"
+def name__T__ = 'single'
+def greeting__T__ = "Hello, ${name}!"
+def short__T__ = "Dear $name,"
+def text__T__ = '''
+    Triple single
+'''
+def gstring__T__ = """
+    Triple double for ${name.toUpperCase()}
+"""
+def number__T__ = 42
@@ -0,0 +1,12 @@
+module Main where
+
+-- __T__A line comment.
+import Data.List (sort)
+
+{- __T__A block comment,
+   spanning __T__multiple lines. -}
+main :: IO ()
+main = do
+  let __T__x = "-- __T__not a comment"
+  {- __T__outer {- __T__nested -} __T__still outer -}
+  putStrLn __T__x -- __T__trailing
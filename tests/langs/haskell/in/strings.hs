@@ -0,0 +1,11 @@
+module Main where
+
+-- A __T__comment with "__T__quotes".
+greeting :: String
+greeting = "__T__Hello, -- __T__not a comment"
+
+main :: IO ()
+main = do
+  let __T__c = 'c'
+  putStrLn ("__T__multi\
+            \__T__line " ++ greeting)
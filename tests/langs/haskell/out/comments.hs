@@ -0,0 +1,12 @@
+module Main where
+
+-- A line comment.
+import Data.List (sort)
+
+{- A block comment,
+   spanning multiple lines. -}
+main :: IO ()
+main = do
+  let __T__x = "-- __T__not a comment"
+  {- outer {- nested -} still outer -}
+  putStrLn __T__x -- trailing
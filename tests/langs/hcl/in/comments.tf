@@ -0,0 +1,17 @@
+# __T__Provider setup
+provider "__T__aws" {
+  __T__region = "__T__eu-central-1 # not a comment"
+}
+
+// __T__The bucket
+resource "__T__aws_s3_bucket" "__T__logs" {
+  __T__bucket = "__T__logs" /* __T__inline */
+
+  /*
+   * __T__Spanning
+   * __T__lines
+   */
+  __T__tags = {
+    __T__Name = "__T__logs" // __T__trailing
+  }
+}
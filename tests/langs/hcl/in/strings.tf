@@ -0,0 +1,16 @@
+# "__T__not a string"
+variable "__T__env" {
+  __T__default = "__T__dev"
+}
+
+resource "__T__aws_instance" "__T__web" {
+  __T__ami  = __T__var.__T__ami_id
+  __T__name = "__T__web-${__T__var.__T__env}-__T__01"
+
+  __T__user_data = <<-EOF
+    __T__#!/bin/bash
+    __T__echo "__T__Hello from ${__T__var.__T__env}"
+  EOF
+
+  __T__count = 2
+}
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::hcl::{Hcl, HclQuery, PremadeHclQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.tf", HclQuery::Premade(PremadeHclQuery::Comments))]
+#[case("strings.tf", HclQuery::Premade(PremadeHclQuery::Strings))]
+fn test_hcl_nuke(#[case] file: &str, #[case] query: HclQuery) {
+    let lang = Hcl::new(query);
+
+    let (input, output) = get_input_output("hcl", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
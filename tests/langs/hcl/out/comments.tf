@@ -0,0 +1,17 @@
+# Provider setup
+provider "__T__aws" {
+  __T__region = "__T__eu-central-1 # not a comment"
+}
+
+// The bucket
+resource "__T__aws_s3_bucket" "__T__logs" {
+  __T__bucket = "__T__logs" /* inline */
+
+  /*
+   * Spanning
+   * lines
+   */
+  __T__tags = {
+    __T__Name = "__T__logs" // trailing
+  }
+}
@@ -0,0 +1,16 @@
+# "__T__not a string"
+variable "env" {
+  __T__default = "dev"
+}
+
+resource "aws_instance" "web" {
+  __T__ami  = __T__var.__T__ami_id
+  __T__name = "web-${__T__var.__T__env}-01"
+
+  __T__user_data = <<-EOF
+    #!/bin/bash
+    echo "Hello from ${__T__var.__T__env}"
+  EOF
+
+  __T__count = 2
+}
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::ini::{Ini, IniQuery, PremadeIniQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.ini", IniQuery::Premade(PremadeIniQuery::Comments))]
+#[case("values.ini", IniQuery::Premade(PremadeIniQuery::Values))]
+fn test_ini_nuke(#[case] file: &str, #[case] query: IniQuery) {
+    let lang = Ini::new(query);
+
+    let (input, output) = get_input_output("ini", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
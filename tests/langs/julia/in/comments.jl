@@ -0,0 +1,17 @@
+# __T__A line comment
+x = 1  # __T__Trailing comment
+
+#= __T__A block comment =#
+#=
+    __T__A multi-line block comment,
+    #= __T__with a nested block comment =#
+    __T__still inside the outer one.
+=#
+
+"""
+    __T__Docstrings are strings, not comments.
+"""
+function f(x)
+    s = "__T__# not a comment"
+    return x #= __T__inline =# + 1
+end
@@ -0,0 +1,15 @@
+# __T__Comments are not strings
+name = "__T__World"
+greeting = "__T__Hello, $__T__name and $(__T__uppercase(name))!"
+
+"""
+    __T__Docstring of the function below, triple-quoted.
+
+    __T__Contains "quotes" and interpolation: $__T__name.
+"""
+function f(x)
+    r = raw"__T__C:\path"
+    return x
+end
+
+c = '__T__c'
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::julia::{Julia, JuliaQuery, PremadeJuliaQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.jl", JuliaQuery::Premade(PremadeJuliaQuery::Comments))]
+#[case("strings.jl", JuliaQuery::Premade(PremadeJuliaQuery::Strings))]
+fn test_julia_nuke(#[case] file: &str, #[case] query: JuliaQuery) {
+    let lang = Julia::new(query);
+
+    let (input, output) = get_input_output("julia", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
@@ -0,0 +1,17 @@
+# A line comment
+x = 1  # Trailing comment
+
+#= A block comment =#
+#=
+    A multi-line block comment,
+    #= with a nested block comment =#
+    still inside the outer one.
+=#
+
+"""
+    __T__Docstrings are strings, not comments.
+"""
+function f(x)
+    s = "__T__# not a comment"
+    return x #= inline =# + 1
+end
@@ -0,0 +1,15 @@
+# __T__Comments are not strings
+name = "World"
+greeting = "Hello, $__T__name and $(__T__uppercase(name))!"
+
+"""
+    Docstring of the function below, triple-quoted.
+
+    Contains "quotes" and interpolation: $__T__name.
+"""
+function f(x)
+    r = raw"C:\path"
+    return x
+end
+
+c = '__T__c'
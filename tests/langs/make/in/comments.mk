@@ -0,0 +1,12 @@
+# Top-level comment__T__
+CC__T__ := gcc # Trailing comment__T__
+CFLAGS__T__ = -O2
+
+# Comment before a rule__T__
+app__T__: main.o__T__ # Comment on target line__T__
+# Comment within a rule__T__
+	$(CC) -o app__T__ main.o__T__
+
+.PHONY: clean__T__
+clean__T__:
+	rm -f app__T__ *.o
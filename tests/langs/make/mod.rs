@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::make::{Make, MakeQuery, PremadeMakeQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.mk", MakeQuery::Premade(PremadeMakeQuery::Comments))]
+#[case("recipes.mk", MakeQuery::Premade(PremadeMakeQuery::Recipes))]
+fn test_make_nuke(#[case] file: &str, #[case] query: MakeQuery) {
+    let lang = Make::new(query);
+
+    let (input, output) = get_input_output("make", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
@@ -0,0 +1,12 @@
+# Top-level comment
+CC__T__ := gcc # Trailing comment__T__
+CFLAGS__T__ = -O2
+
+# Comment before a rule
+app__T__: main.o__T__ # Comment on target line
+# Comment within a rule
+	$(CC) -o app__T__ main.o__T__
+
+.PHONY: clean__T__
+clean__T__:
+	rm -f app__T__ *.o
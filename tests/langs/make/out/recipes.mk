@@ -0,0 +1,9 @@
+# A comment__T__
+CC__T__ := gcc
+
+app__T__: main.o__T__
+	$(CC) -o app main.o
+	@echo "done"
+
+clean__T__:
+	rm -f app
@@ -1,12 +1,31 @@
+mod asm;
+mod clojure;
 mod csharp;
+mod elixir;
+mod fortran;
 mod go;
+mod graphql;
+mod groovy;
+mod haskell;
+mod hcl;
+mod ini;
+mod julia;
+mod make;
+mod ocaml;
+mod perl;
+mod proto;
 mod python;
+mod r;
 mod rust;
+mod scala;
+mod sfc;
+mod tsx;
 mod typescript;
+mod zig;
 
 use std::{fs::read_to_string, path::Path};
 
-use srgn::scoping::{langs::LanguageScoper, regex::Regex, view::ScopedViewBuilder};
+use srgn::scoping::{regex::Regex, view::ScopedViewBuilder, Scoper};
 
 fn get_input_output(lang: &str, file: &str) -> (String, String) {
     let path = Path::new("tests/langs");
@@ -23,7 +42,7 @@ fn get_input_output(lang: &str, file: &str) -> (String, String) {
 /// Convenience function for testing, as deleting a specific character, while
 /// *retaining* it elsewhere, where the language did *not* scope down, is an easy way to
 /// test.
-fn nuke_target(input: &str, lang: &impl LanguageScoper) -> String {
+fn nuke_target(input: &str, lang: &impl Scoper) -> String {
     let mut builder = ScopedViewBuilder::new(input);
 
     builder.explode(lang);
@@ -0,0 +1,8 @@
+(* __T__A comment. *)
+let __T__greeting = "__T__Hello (* __T__not a comment *)"
+
+(* __T__outer (* __T__nested (* __T__deeper *) *) __T__still outer *)
+let __T__add __T__a __T__b = __T__a + __T__b
+
+(* __T__A comment with a "__T__string (* __T__inside *)" in it. *)
+let () = print_endline __T__greeting (* __T__trailing *)
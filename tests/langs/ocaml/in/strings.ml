@@ -0,0 +1,8 @@
+(* A __T__comment with a "__T__string" inside, not a separate string. *)
+let __T__greeting = "__T__Hello, (* __T__world *)"
+let __T__quoted = {|__T__raw "__T__quotes" here|}
+let __T__tagged = {id|__T__tagged|id}
+
+let () =
+  print_endline __T__greeting;
+  print_endline ("__T__escaped \"__T__quote\"" ^ __T__quoted)
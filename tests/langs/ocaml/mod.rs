@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::ocaml::{Ocaml, OcamlQuery, PremadeOcamlQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.ml", OcamlQuery::Premade(PremadeOcamlQuery::Comments))]
+#[case("strings.ml", OcamlQuery::Premade(PremadeOcamlQuery::Strings))]
+fn test_ocaml_nuke(#[case] file: &str, #[case] query: OcamlQuery) {
+    let lang = Ocaml::new(query);
+
+    let (input, output) = get_input_output("ocaml", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
@@ -0,0 +1,8 @@
+(* A comment. *)
+let __T__greeting = "__T__Hello (* __T__not a comment *)"
+
+(* outer (* nested (* deeper *) *) still outer *)
+let __T__add __T__a __T__b = __T__a + __T__b
+
+(* A comment with a "string (* inside *)" in it. *)
+let () = print_endline __T__greeting (* trailing *)
@@ -0,0 +1,8 @@
+(* A __T__comment with a "__T__string" inside, not a separate string. *)
+let __T__greeting = "Hello, (* world *)"
+let __T__quoted = {|raw "quotes" here|}
+let __T__tagged = {id|tagged|id}
+
+let () =
+  print_endline __T__greeting;
+  print_endline ("escaped \"quote\"" ^ __T__quoted)
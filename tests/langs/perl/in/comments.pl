@@ -0,0 +1,19 @@
+#!/usr/bin/perl
+use strict;
+use warnings;
+
+# __T__Greets someone.
+sub greet__T__ {
+    my ($name__T__) = @_; # __T__the name
+    print "Hello, $name__T__!\n";
+}
+
+=pod
+
+=head1 __T__NAME
+
+__T__greet - say __T__hello
+
+=cut
+
+greet__T__("# not a comment");
@@ -0,0 +1,11 @@
+use strict;
+
+my $a__T__ = '__T__single';
+my $b__T__ = "__T__double";
+my $c__T__ = q/__T__quoted/;
+my $d__T__ = qq{__T__interpolated $a__T__};
+my $e__T__ = <<"END";
+__T__heredoc line
+END
+
+print $a__T__, $b__T__; # __T__comment
@@ -0,0 +1,19 @@
+#!/usr/bin/perl
+use strict;
+use warnings;
+
+# Greets someone.
+sub greet__T__ {
+    my ($name__T__) = @_; # the name
+    print "Hello, $name__T__!\n";
+}
+
+=pod
+
+=head1 NAME
+
+greet - say hello
+
+=cut
+
+greet__T__("# not a comment");
@@ -0,0 +1,11 @@
+use strict;
+
+my $a__T__ = 'single';
+my $b__T__ = "double";
+my $c__T__ = q/quoted/;
+my $d__T__ = qq{interpolated $a};
+my $e__T__ = <<"END";
+heredoc line
+END
+
+print $a__T__, $b__T__; # __T__comment
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::proto::{PremadeProtoQuery, Proto, ProtoQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.proto", ProtoQuery::Premade(PremadeProtoQuery::Comments))]
+#[case("strings.proto", ProtoQuery::Premade(PremadeProtoQuery::Strings))]
+fn test_proto_nuke(#[case] file: &str, #[case] query: ProtoQuery) {
+    let lang = Proto::new(query);
+
+    let (input, output) = get_input_output("proto", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
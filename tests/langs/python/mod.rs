@@ -1,5 +1,12 @@
 use rstest::rstest;
-use srgn::scoping::langs::python::{PremadePythonQuery, Python, PythonQuery};
+use srgn::scoping::{
+    langs::{
+        python::{CustomPythonQuery, PremadePythonQuery, Python, PythonQuery},
+        CaptureError,
+    },
+    view::ScopedViewBuilder,
+};
+use std::str::FromStr;
 
 use super::{get_input_output, nuke_target};
 
@@ -25,3 +32,56 @@ fn test_python_nuke(#[case] file: &str, #[case] query: PythonQuery) {
 
     assert_eq!(result, output);
 }
+
+#[rstest]
+#[case(None, "x = y\n")]
+#[case(Some("key"), "x = __T__y\n")]
+#[case(Some("value"), "__T__x = y\n")]
+fn test_python_capture_selection(#[case] capture: Option<&str>, #[case] expected: &str) {
+    let query = PythonQuery::Custom(
+        CustomPythonQuery::from_str("(assignment left: (identifier) @key right: (_) @value)")
+            .unwrap(),
+    );
+    let lang = Python::new(query);
+    let lang = match capture {
+        Some(name) => lang.with_capture(name).unwrap(),
+        None => lang,
+    };
+
+    let result = nuke_target("__T__x = __T__y\n", &lang);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_python_capture_selection_unknown_capture() {
+    let query = PythonQuery::Custom(CustomPythonQuery::from_str("(identifier) @name").unwrap());
+
+    let err = Python::new(query).with_capture("missing").unwrap_err();
+
+    assert_eq!(
+        err,
+        CaptureError::NoSuchCapture {
+            name: "missing".into(),
+            available: vec!["name".into()],
+        }
+    );
+}
+
+#[test]
+fn test_python_trim_trailing_comments() {
+    let lang = Python::new(PythonQuery::Premade(PremadePythonQuery::Comments));
+
+    let input = "x = 1  # comment  \ns = \"\"\"text  \nmore\"\"\"  \n# other\t\n";
+
+    let mut builder = ScopedViewBuilder::new(input);
+    builder.explode(&lang);
+    let mut view = builder.build();
+    view.trim_trailing();
+
+    // Whitespace in string literals and code is left alone, only comments are touched.
+    assert_eq!(
+        view.to_string(),
+        "x = 1  # comment\ns = \"\"\"text  \nmore\"\"\"  \n# other\n"
+    );
+}
@@ -0,0 +1,34 @@
+use rstest::rstest;
+use srgn::scoping::{
+    langs::r::{PremadeRQuery, RQuery, R},
+    view::ScopedViewBuilder,
+};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.R", RQuery::Premade(PremadeRQuery::Comments))]
+#[case("doc-comments.R", RQuery::Premade(PremadeRQuery::DocComments))]
+#[case("strings.R", RQuery::Premade(PremadeRQuery::Strings))]
+fn test_r_nuke(#[case] file: &str, #[case] query: RQuery) {
+    let lang = R::new(query);
+
+    let (input, output) = get_input_output("r", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
+
+#[rstest]
+#[case(PremadeRQuery::Comments, "#' roxygen\n# PLAIN\nx <- 1\n")]
+#[case(PremadeRQuery::DocComments, "#' ROXYGEN\n# plain\nx <- 1\n")]
+fn test_r_roxygen_vs_plain_comments(#[case] query: PremadeRQuery, #[case] expected: &str) {
+    let lang = R::new(RQuery::Premade(query));
+
+    let mut builder = ScopedViewBuilder::new("#' roxygen\n# plain\nx <- 1\n");
+    builder.explode(&lang);
+    let mut view = builder.build();
+    view.upper();
+
+    assert_eq!(view.to_string(), expected);
+}
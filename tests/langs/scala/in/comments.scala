@@ -0,0 +1,14 @@
+package __T__example
+
+/** __T__Scaladoc for the object.
+  *
+  * @param __T__name __T__unused
+  */
+object __T__Main {
+  // __T__A line comment.
+  val __T__url = "https://__T__example.com // __T__not a comment"
+
+  /* __T__A block comment. */
+  def __T__main(__T__args: Array[String]): Unit =
+    println(__T__url) // __T__trailing
+}
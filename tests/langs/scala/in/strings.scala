@@ -0,0 +1,8 @@
+object __T__Main {
+  // A "__T__comment"
+  val __T__name = "__T__World"
+  val __T__greeting = s"__T__Hello, $__T__name and ${__T__name.__T__length}!"
+  val __T__block = """__T__Triple "__T__quoted"
+    __T__string"""
+  val __T__raw = raw"__T__no\n__T__escapes"
+}
@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::scala::{PremadeScalaQuery, Scala, ScalaQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.scala", ScalaQuery::Premade(PremadeScalaQuery::Comments))]
+#[case("strings.scala", ScalaQuery::Premade(PremadeScalaQuery::Strings))]
+fn test_scala_nuke(#[case] file: &str, #[case] query: ScalaQuery) {
+    let lang = Scala::new(query);
+
+    let (input, output) = get_input_output("scala", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
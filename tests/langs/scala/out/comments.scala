@@ -0,0 +1,14 @@
+package __T__example
+
+/** Scaladoc for the object.
+  *
+  * @param name unused
+  */
+object __T__Main {
+  // A line comment.
+  val __T__url = "https://__T__example.com // __T__not a comment"
+
+  /* A block comment. */
+  def __T__main(__T__args: Array[String]): Unit =
+    println(__T__url) // trailing
+}
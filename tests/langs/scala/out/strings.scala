@@ -0,0 +1,8 @@
+object __T__Main {
+  // A "__T__comment"
+  val __T__name = "World"
+  val __T__greeting = s"Hello, $__T__name and ${__T__name.__T__length}!"
+  val __T__block = """Triple "quoted"
+    string"""
+  val __T__raw = raw"no\nescapes"
+}
@@ -0,0 +1,17 @@
+use rstest::rstest;
+use srgn::scoping::langs::sfc::{PremadeSfcQuery, SingleFileComponent};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.vue", PremadeSfcQuery::Comments)]
+#[case("strings.vue", PremadeSfcQuery::Strings)]
+#[case("comments.svelte", PremadeSfcQuery::Comments)]
+fn test_sfc_nuke(#[case] file: &str, #[case] query: PremadeSfcQuery) {
+    let lang = SingleFileComponent::new(query);
+
+    let (input, output) = get_input_output("sfc", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
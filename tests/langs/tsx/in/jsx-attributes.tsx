@@ -0,0 +1,10 @@
+export function __T__Form(__T__props: __T__Props) {
+    const __T__attrs = { __T__id: "__T__form" };
+
+    return (
+        <__T__form __T__className="__T__form" __T__onSubmit={__T__props.__T__onSubmit} {...__T__attrs}>
+            <__T__input __T__type="__T__text" {...__T__props.__T__input} __T__disabled />
+            __T__Submit
+        </__T__form>
+    );
+}
@@ -0,0 +1,16 @@
+export function Counter__T__({ count__T__, items__T__, onReset__T__ }: Props__T__) {
+    const label__T__ = `Count: ${count__T__}`;
+
+    return (
+        <div className__T__={label__T__} onClick__T__={onReset__T__} {...rest__T__}>
+            <span>{count__T__}</span>
+            {count__T__ > 10 && <strong__T__>{"High__T__"}</strong__T__>}
+            {items__T__.map((item__T__) => (
+                <li key__T__={item__T__.id__T__}>{item__T__.name__T__}</li>
+            ))}
+            {/* A comment__T__ */}
+            <input value__T__={count__T__} />
+            Plain text__T__
+        </div>
+    );
+}
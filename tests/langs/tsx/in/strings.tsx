@@ -0,0 +1,8 @@
+const __T__label: __T__string = "__T__Click";
+const __T__title = `__T__Hello, ${__T__name}__T__!`;
+
+export const __T__Button = () => (
+    <__T__button __T__className="__T__primary" __T__title={__T__title}>
+        __T__Text is not a string
+    </__T__button>
+);
@@ -0,0 +1,41 @@
+use rstest::rstest;
+use srgn::scoping::{
+    langs::tsx::{PremadeTsxQuery, Tsx, TsxQuery},
+    view::ScopedViewBuilder,
+};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.tsx", TsxQuery::Premade(PremadeTsxQuery::Comments))]
+#[case("strings.tsx", TsxQuery::Premade(PremadeTsxQuery::Strings))]
+#[case(
+    "jsx-attributes.tsx",
+    TsxQuery::Premade(PremadeTsxQuery::JsxAttributes)
+)]
+#[case(
+    "jsx-expressions.tsx",
+    TsxQuery::Premade(PremadeTsxQuery::JsxExpressions)
+)]
+fn test_tsx_nuke(#[case] file: &str, #[case] query: TsxQuery) {
+    let lang = Tsx::new(query);
+
+    let (input, output) = get_input_output("tsx", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
+
+#[test]
+fn test_tsx_jsx_expressions_exclude_attributes() {
+    let lang = Tsx::new(TsxQuery::Premade(PremadeTsxQuery::JsxExpressions));
+
+    let input = "const a = <p title={count}>{count}</p>;";
+
+    let mut builder = ScopedViewBuilder::new(input);
+    builder.explode(&lang);
+    let mut view = builder.build();
+    view.upper();
+
+    assert_eq!(view.to_string(), "const a = <p title={count}>{COUNT}</p>;");
+}
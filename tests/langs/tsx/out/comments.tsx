@@ -0,0 +1,10 @@
+// A button
+/* Block */
+export function __T__Button(__T__props: __T__Props) {
+    return (
+        <__T__button __T__type="__T__button" __T__onClick={__T__props.__T__onClick}>
+            {/* JSX comment */}
+            __T__Click // not a comment
+        </__T__button>
+    );
+}
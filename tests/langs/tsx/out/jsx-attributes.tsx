@@ -0,0 +1,10 @@
+export function __T__Form(__T__props: __T__Props) {
+    const __T__attrs = { __T__id: "__T__form" };
+
+    return (
+        <__T__form className="form" onSubmit={props.onSubmit} {...attrs}>
+            <__T__input type="text" {...props.input} disabled />
+            __T__Submit
+        </__T__form>
+    );
+}
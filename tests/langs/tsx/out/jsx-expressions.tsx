@@ -0,0 +1,16 @@
+export function Counter__T__({ count__T__, items__T__, onReset__T__ }: Props__T__) {
+    const label__T__ = `Count: ${count__T__}`;
+
+    return (
+        <div className__T__={label__T__} onClick__T__={onReset__T__} {...rest__T__}>
+            <span>{count}</span>
+            {count > 10 && <strong>{"High"}</strong>}
+            {items.map((item) => (
+                <li key={item.id}>{item.name}</li>
+            ))}
+            {/* A comment */}
+            <input value__T__={count__T__} />
+            Plain text__T__
+        </div>
+    );
+}
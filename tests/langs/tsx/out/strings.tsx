@@ -0,0 +1,8 @@
+const __T__label: __T__string = "Click";
+const __T__title = `Hello, ${__T__name}!`;
+
+export const __T__Button = () => (
+    <__T__button __T__className="primary" __T__title={__T__title}>
+        __T__Text is not a string
+    </__T__button>
+);
@@ -0,0 +1,8 @@
+const __T__names: __T__string[] = ["__T__a", "__T__b"];
+const __T__pair: [__T__string, __T__number] = ["__T__x", 1];
+const __T__matrix: __T__number[][] = [[1, 2], [3, 4]];
+const __T__generic: Array<__T__string> = [];
+
+function __T__zip(__T__a: __T__string[], __T__b: __T__number[]): [__T__string, __T__number][] {
+    return __T__a.map((__T__x, __T__i) => [__T__x, __T__b[__T__i]]);
+}
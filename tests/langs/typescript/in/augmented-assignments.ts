@@ -0,0 +1,12 @@
+let __T__total = 0;
+let __T__name: string | undefined;
+let __T__flag = false;
+
+__T__total += 1;
+__T__total -= __T__total;
+__T__name ??= "default";
+__T__flag ||= true;
+__T__flag &&= __T__total > 0;
+
+__T__total = 42;
+__T__name = "plain";
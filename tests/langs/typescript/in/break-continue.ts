@@ -0,0 +1,17 @@
+__T__outer: for (const __T__row of __T__rows) {
+    for (const __T__cell of __T__row) {
+        if (__T__cell < 0) {
+            continue __T__outer;
+        }
+        if (__T__cell === 0) {
+            break __T__outer;
+        }
+    }
+}
+
+switch (__T__x) {
+    case 1:
+        break;
+    default:
+        __T__log("__T__break continue");
+}
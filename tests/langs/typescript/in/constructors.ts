@@ -0,0 +1,27 @@
+class __T__Service {
+    constructor(
+        private readonly __T__http: __T__HttpClient,
+        public __T__logger: __T__Logger,
+        @__T__Inject(__T__CONFIG) __T__config: __T__Config,
+    ) {
+        __T__this.__T__logger.__T__debug("__T__created");
+    }
+
+    __T__constructorLike(): void {}
+
+    __T__describe(): string {
+        return "__T__constructor(" + __T__this.__T__constructor.__T__name;
+    }
+
+    __T__build(): __T__Service {
+        return new __T__Service(__T__this.__T__http, __T__this.__T__logger, __T__this.__T__config);
+    }
+}
+
+class __T__Plain {
+    public constructor(__T__x: number) {}
+}
+
+const __T__notAClass = {
+    constructor(__T__x: number) {},
+};
@@ -0,0 +1,15 @@
+function main__T__(input__T__: string): void {
+    const trimmed__T__ = input__T__.trim();
+    console.log(trimmed__T__);
+}
+
+let total__T__ = 0;
+var legacy__T__ = 1;
+main__T__("x");
+total__T__ += 1;
+
+interface Shape__T__ { area__T__(): number }
+type Id__T__ = string;
+enum Color__T__ { Red__T__ }
+class Box__T__ {}
+declare const env__T__: string;
@@ -0,0 +1,16 @@
+const { a__T__, b__T__ } = obj__T__;
+const [first__T__, second__T__] = list__T__;
+const { nested__T__: { deep__T__ }, arr__T__: [x__T__, y__T__] } = config__T__;
+const { retries__T__ = 3, timeout__T__ = defaults__T__.timeout } = options__T__;
+const [head__T__ = 0, ...tail__T__] = numbers__T__;
+let { ...rest__T__ } = obj__T__;
+
+function handle__T__({ id__T__, name__T__ = "anonymous__T__" }: User__T__): void {
+    const plain__T__ = id__T__;
+}
+
+for (const [key__T__, value__T__] of Object.entries(map__T__)) {
+    console.log(key__T__, value__T__);
+}
+
+const literal__T__ = { a__T__: 1, b__T__: [2, 3] };
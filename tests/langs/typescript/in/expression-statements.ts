@@ -0,0 +1,12 @@
+function main__T__(input__T__: string): void {
+    const trimmed__T__ = input__T__.trim();
+    console.log(trimmed__T__);
+    count__T__ += 1;
+    if (trimmed__T__) {
+        notify__T__(trimmed__T__);
+    }
+    return;
+}
+
+let total__T__ = 0;
+main__T__("x");
@@ -0,0 +1,19 @@
+function* countUp__T__(limit__T__: number) {
+    for (let i__T__ = 0; i__T__ < limit__T__; i__T__++) {
+        yield i__T__;
+    }
+}
+
+const delegating__T__ = function* () {
+    yield* countUp__T__(3);
+};
+
+function regular__T__(x__T__: number): number {
+    return x__T__ * 2;
+}
+
+class Sequence__T__ {
+    *items__T__() {
+        yield 1;
+    }
+}
@@ -0,0 +1,22 @@
+class __T__Temperature {
+    private __T__celsius = 0;
+
+    get __T__value(): number {
+        return this.__T__celsius;
+    }
+
+    set __T__value(__T__v: number) {
+        this.__T__celsius = __T__v;
+    }
+
+    __T__get(): number {
+        return this.__T__celsius;
+    }
+}
+
+const __T__point = {
+    get __T__x() {
+        return 1;
+    },
+    set __T__x(__T__v: number) {},
+};
@@ -0,0 +1,14 @@
+import { a__T__ } from "./foo__T__";
+import b__T__ from '../bar__T__';
+import * as c__T__ from "@scope/baz__T__";
+import "./side-effect__T__";
+import type { T__T__ } from "./types__T__";
+
+export { d__T__ } from "./reexport__T__";
+export * from './everything__T__';
+export const e__T__ = "./not-a-source__T__";
+export { e__T__ as f__T__ };
+
+const lazy__T__ = import("./dynamic__T__");
+const g__T__ = require("./required__T__");
+console.log("from './nope__T__'");
@@ -0,0 +1,12 @@
+__T__outer: for (const __T__row of __T__rows) {
+    for (const __T__cell of __T__row) {
+        if (__T__cell === 0) {
+            break __T__outer;
+        }
+    }
+}
+
+const __T__obj = { __T__outer: 1 };
+for (const __T__item of __T__items) {
+    __T__process(__T__item);
+}
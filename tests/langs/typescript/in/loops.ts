@@ -0,0 +1,31 @@
+function process__T__(items__T__: number[]): number {
+    let total__T__ = 0;
+
+    for (let i__T__ = 0; i__T__ < items__T__.length; i__T__++) {
+        total__T__ += items__T__[i__T__];
+    }
+
+    for (const key__T__ in items__T__) {
+        console.log(key__T__);
+    }
+
+    for (const item__T__ of items__T__) {
+        if (item__T__ > 10) {
+            break;
+        }
+    }
+
+    while (total__T__ > 100) {
+        total__T__ /= 2;
+    }
+
+    do {
+        total__T__ -= 1;
+    } while (total__T__ > 50);
+
+    if (total__T__ < 0) {
+        total__T__ = 0;
+    }
+
+    return total__T__;
+}
@@ -0,0 +1,27 @@
+const __T__outside = 1;
+
+namespace Outer__T__ {
+    export const value__T__ = 1;
+
+    export namespace Inner__T__ {
+        export function greet__T__(): string {
+            return "hello__T__";
+        }
+    }
+
+    namespace Deeply.Nested__T__.Name {
+        const x__T__ = 2;
+    }
+}
+
+module Legacy__T__ {
+    export type Id__T__ = string;
+}
+
+declare module "some-module__T__" {
+    export function f__T__(): void;
+}
+
+function __T__notANamespace() {
+    return "__T__";
+}
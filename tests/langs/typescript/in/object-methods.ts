@@ -0,0 +1,20 @@
+const counter__T__ = {
+    count__T__: 0,
+    increment__T__() {
+        this.count__T__ += 1;
+    },
+    get double__T__() {
+        return this.count__T__ * 2;
+    },
+    set value__T__(v__T__: number) {
+        this.count__T__ = v__T__;
+    },
+    reset__T__: function () {
+        this.count__T__ = 0;
+    },
+    arrow__T__: () => 0,
+};
+
+class Counter__T__ {
+    increment__T__() {}
+}
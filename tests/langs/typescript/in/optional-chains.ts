@@ -0,0 +1,10 @@
+const __T__user = { __T__name: "__T__x", __T__tags: ["__T__a"] };
+
+const __T__name = __T__user?.__T__name;
+const __T__tag = __T__user?.__T__tags?.[0];
+const __T__length = __T__user.__T__name?.__T__trim();
+const __T__called = __T__user.__T__greet?.();
+const __T__plain = __T__user.__T__name;
+
+const __T__ternary = __T__user ? __T__user.__T__name : "__T__none";
+const __T__fallback = __T__user ?? "__T__none";
@@ -0,0 +1,17 @@
+class Counter {
+    #__T__count = __T__0;
+    static #__T__instances: number = __T__0;
+    __T__public = 1;
+    private __T__legacy = 2;
+
+    increment(): number {
+        this.#__T__count += 1;
+        return this.#__T__count;
+    }
+
+    #__T__reset() {
+        this.#__T__count = __T__zero();
+    }
+}
+
+const c = "__T__#count";
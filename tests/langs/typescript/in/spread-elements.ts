@@ -0,0 +1,12 @@
+const __T__a = [1, 2];
+const __T__b = [0, ...__T__a, 3];
+
+const __T__base = { __T__x: 1 };
+const __T__extended = { ...__T__base, __T__y: 2 };
+
+const [__T__first, ...__T__others] = __T__b;
+const { __T__x, ...__T__remaining } = __T__extended;
+
+function __T__sum(...__T__numbers: number[]): number {
+    return Math.max(...__T__numbers);
+}
@@ -0,0 +1,10 @@
+const __T__a = true;
+const __T__c = false;
+
+const __T__simple = __T__a ? 1 : 2;
+const __T__chained = __T__a ? "b" : __T__c ? "d" : "e";
+const __T__nested = (__T__a ? __T__c : !__T__c) ? __T__a : __T__c;
+
+if (__T__a) {
+    __T__console.log(__T__a && __T__c);
+}
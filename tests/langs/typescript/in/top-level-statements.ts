@@ -0,0 +1,16 @@
+// __T__A comment is not a statement
+import { __T__a } from "./a";
+
+const __T__x = 1;
+
+/* __T__Neither is this one */
+export function __T__f(__T__y: number): number {
+    if (__T__y > 0) {
+        return __T__y;
+    }
+    return -__T__y;
+}
+
+class __T__C {}
+
+__T__f(__T__x);
@@ -0,0 +1,19 @@
+function risky__T__(): number {
+    try {
+        const a__T__ = compute();
+        try {
+            return a__T__ / inner();
+        } catch (inner__T__) {
+            console.error(inner__T__);
+            throw inner__T__;
+        } finally {
+            cleanupInner__T__();
+        }
+    } catch (e__T__) {
+        return -1;
+    } finally {
+        cleanup__T__();
+    }
+}
+
+const outside__T__ = risky__T__();
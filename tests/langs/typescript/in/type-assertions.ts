@@ -0,0 +1,12 @@
+interface __T__Foo {
+    __T__a: number;
+}
+
+declare const __T__x: unknown;
+
+const __T__asserted = __T__x as __T__Foo;
+const __T__escaped = __T__x as any;
+const __T__checked = { __T__a: 1 } satisfies __T__Foo;
+const __T__frozen = [__T__x] as const;
+const __T__casted = <__T__Foo>__T__x;
+const __T__annotated: __T__Foo = { __T__a: 2 };
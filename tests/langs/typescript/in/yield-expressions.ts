@@ -0,0 +1,11 @@
+function* numbers__T__(): Generator<number> {
+    yield 1__T__;
+    const received__T__ = yield value__T__;
+    yield* other__T__();
+    yield;
+    return received__T__;
+}
+
+function plain__T__() {
+    return 42;
+}
@@ -1,5 +1,14 @@
 use rstest::rstest;
-use srgn::scoping::langs::typescript::{PremadeTypeScriptQuery, TypeScript, TypeScriptQuery};
+use srgn::scoping::{
+    exclusion::Exclusion,
+    langs::typescript::{
+        CustomTypeScriptQuery, PremadeTypeScriptQuery, TagsError, TypeScript, TypeScriptQuery,
+    },
+    regex::Regex,
+    scope::{ROScope, Scope::In},
+    view::ScopedViewBuilder,
+    Scoper,
+};
 
 use super::{get_input_output, nuke_target};
 
@@ -16,6 +25,113 @@ use super::{get_input_output, nuke_target};
     "imports.ts",
     TypeScriptQuery::Premade(PremadeTypeScriptQuery::Imports)
 )]
+#[case(
+    "namespaces.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Namespaces)
+)]
+#[case("throws.ts", TypeScriptQuery::Premade(PremadeTypeScriptQuery::Throws))]
+#[case(
+    "try-blocks.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::TryBlocks)
+)]
+#[case(
+    "array-types.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::ArrayTypes)
+)]
+#[case(
+    "spread-elements.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::SpreadElements)
+)]
+#[case(
+    "optional-chains.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::OptionalChains)
+)]
+#[case(
+    "non-null-assertions.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::NonNullAssertions)
+)]
+#[case(
+    "break-continue.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::BreakContinue)
+)]
+#[case("labels.ts", TypeScriptQuery::Premade(PremadeTypeScriptQuery::Labels))]
+#[case(
+    "augmented-assignments.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::AugmentedAssignments)
+)]
+#[case(
+    "getters.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Getters)
+)]
+#[case(
+    "setters.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Setters)
+)]
+#[case(
+    "ternaries.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Ternaries)
+)]
+#[case(
+    "type-assertions.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::TypeAssertions)
+)]
+#[case(
+    "default-exports.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::DefaultExports)
+)]
+#[case(
+    "named-exports.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::NamedExports)
+)]
+#[case(
+    "constructors.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Constructors)
+)]
+#[case(
+    "import-specifiers.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::ImportSpecifiers)
+)]
+#[case(
+    "tagged-comments.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::TaggedComments)
+)]
+#[case(
+    "top-level-statements.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::TopLevelStatements)
+)]
+#[case(
+    "private-fields.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::PrivateFields)
+)]
+#[case("loops.ts", TypeScriptQuery::Premade(PremadeTypeScriptQuery::Loops))]
+#[case(
+    "destructuring-patterns.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::DestructuringPatterns)
+)]
+#[case(
+    "generators.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Generators)
+)]
+#[case(
+    "yield-expressions.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::YieldExpressions)
+)]
+#[case(
+    "import-sources.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::ImportSources)
+)]
+#[case(
+    "expression-statements.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::ExpressionStatements)
+)]
+#[case(
+    "declarations.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::Declarations)
+)]
+#[case(
+    "object-methods.ts",
+    TypeScriptQuery::Premade(PremadeTypeScriptQuery::ObjectMethods)
+)]
 fn test_typescript_nuke(#[case] file: &str, #[case] query: TypeScriptQuery) {
     let lang = TypeScript::new(query);
 
@@ -24,3 +140,113 @@ fn test_typescript_nuke(#[case] file: &str, #[case] query: TypeScriptQuery) {
 
     assert_eq!(result, output);
 }
+
+#[test]
+fn test_typescript_surround_strings() {
+    let lang = TypeScript::new(TypeScriptQuery::Premade(PremadeTypeScriptQuery::Strings));
+
+    let input = r#"const a = 'single'; const b = "double"; // 'not a string'"#;
+
+    let mut builder = ScopedViewBuilder::new(input);
+    builder.explode(&lang);
+    let mut view = builder.build();
+    view.surround("`".to_string(), "`".to_string());
+
+    assert_eq!(
+        view.to_string(),
+        r#"const a = `'single'`; const b = `"double"`; // 'not a string'"#
+    );
+}
+
+#[test]
+fn test_typescript_strings_excluding_urls() {
+    let lang = TypeScript::new(TypeScriptQuery::Premade(PremadeTypeScriptQuery::Strings));
+    let exclusion = Exclusion::new(Regex::try_from(r"^.https?://.*".to_string()).unwrap());
+
+    let input =
+        r#"const urls = ["https://example.com", 'http://x.org', "hello", "see https://y"];"#;
+
+    let mut builder = ScopedViewBuilder::new(input);
+    builder.explode(&lang).explode(&exclusion);
+    let mut view = builder.build();
+    view.upper();
+
+    assert_eq!(
+        view.to_string(),
+        r#"const urls = ["https://example.com", 'http://x.org', "HELLO", "SEE HTTPS://Y"];"#
+    );
+}
+
+#[rstest]
+#[case(&["NOTE"], "// NOTE: x\n// TODO: y\n", "// NOTE: X\n// TODO: y\n")]
+#[case(&["TODO", "NOTE"], "// NOTE: x\n// TODO: y\n", "// NOTE: X\n// TODO: Y\n")]
+#[case(&["C++"], "// C++ only\n// C only\n", "// C++ ONLY\n// C only\n")]
+#[case(&[r#"a"b\c"#], "// a\"b\\c\n// abc\n", "// A\"B\\C\n// abc\n")]
+fn test_typescript_tagged_comments_custom_tags(
+    #[case] tags: &[&str],
+    #[case] input: &str,
+    #[case] expected: &str,
+) {
+    let lang = TypeScript::new(TypeScriptQuery::Custom(
+        CustomTypeScriptQuery::tagged_comments(tags).unwrap(),
+    ));
+
+    let mut builder = ScopedViewBuilder::new(input);
+    builder.explode(&lang);
+    let mut view = builder.build();
+    view.upper();
+
+    assert_eq!(view.to_string(), expected);
+}
+
+#[rstest]
+#[case(&[], TagsError::NoTags)]
+#[case(&[""], TagsError::EmptyTag)]
+#[case(&["TODO", ""], TagsError::EmptyTag)]
+fn test_typescript_tagged_comments_rejects_empty_tags(
+    #[case] tags: &[&str],
+    #[case] expected: TagsError,
+) {
+    assert_eq!(
+        CustomTypeScriptQuery::tagged_comments(tags).unwrap_err(),
+        expected
+    );
+}
+
+#[rstest]
+#[case("", 0)]
+#[case("// Only a comment", 0)]
+#[case("const a = 1;", 1)]
+#[case("const a = 1; const b = 2;", 2)]
+#[case(
+    r#"
+import { x } from "x";
+
+// Comment
+export interface I {
+    a: string;
+}
+
+function f() {
+    const nested = 1;
+    return nested;
+}
+
+f();
+"#,
+    4
+)]
+fn test_typescript_top_level_statements_count(#[case] input: &str, #[case] expected: usize) {
+    let lang = TypeScript::new(TypeScriptQuery::Premade(
+        PremadeTypeScriptQuery::TopLevelStatements,
+    ));
+
+    let n = lang
+        .scope(input)
+        .0
+        .iter()
+        .filter(|scope| matches!(scope, ROScope(In(_))))
+        .count();
+
+    assert_eq!(n, expected);
+}
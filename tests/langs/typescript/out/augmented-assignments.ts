@@ -0,0 +1,12 @@
+let __T__total = 0;
+let __T__name: string | undefined;
+let __T__flag = false;
+
+total += 1;
+total -= total;
+name ??= "default";
+flag ||= true;
+flag &&= total > 0;
+
+__T__total = 42;
+__T__name = "plain";
@@ -0,0 +1,15 @@
+function main(input: string): void {
+    const trimmed = input.trim();
+    console.log(trimmed);
+}
+
+let total = 0;
+var legacy = 1;
+main__T__("x");
+total__T__ += 1;
+
+interface Shape { area(): number }
+type Id = string;
+enum Color { Red }
+class Box {}
+declare const env: string;
@@ -0,0 +1,18 @@
+import { __T__helper } from "./helper";
+
+export default function main() {
+    return helper();
+}
+
+export default config;
+
+export { __T__helper, __T__main as __T__entry };
+export type { __T__Options } from "./options";
+export * from "./utils";
+export * as __T__models from "./models";
+export { default as __T__Widget } from "./widget";
+
+export const __T__VERSION = "1.0";
+export interface __T__Options {}
+
+const __T__internal = __T__helper();
@@ -0,0 +1,16 @@
+const { a, b } = obj__T__;
+const [first, second] = list__T__;
+const { nested: { deep }, arr: [x, y] } = config__T__;
+const { retries = 3, timeout = defaults.timeout } = options__T__;
+const [head = 0, ...tail] = numbers__T__;
+let { ...rest } = obj__T__;
+
+function handle__T__({ id, name = "anonymous" }: User__T__): void {
+    const plain__T__ = id__T__;
+}
+
+for (const [key, value] of Object.entries(map__T__)) {
+    console.log(key__T__, value__T__);
+}
+
+const literal__T__ = { a__T__: 1, b__T__: [2, 3] };
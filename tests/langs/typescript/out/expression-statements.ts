@@ -0,0 +1,12 @@
+function main__T__(input__T__: string): void {
+    const trimmed__T__ = input__T__.trim();
+    console.log(trimmed);
+    count += 1;
+    if (trimmed__T__) {
+        notify(trimmed);
+    }
+    return;
+}
+
+let total__T__ = 0;
+main("x");
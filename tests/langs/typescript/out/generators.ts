@@ -0,0 +1,19 @@
+function* countUp(limit: number) {
+    for (let i = 0; i < limit; i++) {
+        yield i;
+    }
+}
+
+const delegating__T__ = function* () {
+    yield* countUp(3);
+};
+
+function regular__T__(x__T__: number): number {
+    return x__T__ * 2;
+}
+
+class Sequence__T__ {
+    *items__T__() {
+        yield 1;
+    }
+}
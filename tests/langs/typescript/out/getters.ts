@@ -0,0 +1,22 @@
+class __T__Temperature {
+    private __T__celsius = 0;
+
+    get value(): number {
+        return this.celsius;
+    }
+
+    set __T__value(__T__v: number) {
+        this.__T__celsius = __T__v;
+    }
+
+    __T__get(): number {
+        return this.__T__celsius;
+    }
+}
+
+const __T__point = {
+    get x() {
+        return 1;
+    },
+    set __T__x(__T__v: number) {},
+};
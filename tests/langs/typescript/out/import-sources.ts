@@ -0,0 +1,14 @@
+import { a__T__ } from "./foo";
+import b__T__ from '../bar';
+import * as c__T__ from "@scope/baz";
+import "./side-effect";
+import type { T__T__ } from "./types";
+
+export { d__T__ } from "./reexport";
+export * from './everything';
+export const e__T__ = "./not-a-source__T__";
+export { e__T__ as f__T__ };
+
+const lazy__T__ = import("./dynamic__T__");
+const g__T__ = require("./required__T__");
+console.log("from './nope__T__'");
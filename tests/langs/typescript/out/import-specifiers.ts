@@ -0,0 +1,5 @@
+import Default, { a, b as c } from '__T__x';
+import * as ns from "__T__y";
+import "__T__side-effect";
+
+const __T__a = __T__Default(__T__ns, __T__c);
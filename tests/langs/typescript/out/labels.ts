@@ -0,0 +1,12 @@
+outer: for (const row of rows) {
+    for (const cell of row) {
+        if (cell === 0) {
+            break outer;
+        }
+    }
+}
+
+const __T__obj = { __T__outer: 1 };
+for (const __T__item of __T__items) {
+    __T__process(__T__item);
+}
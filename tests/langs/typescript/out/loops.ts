@@ -0,0 +1,31 @@
+function process__T__(items__T__: number[]): number {
+    let total__T__ = 0;
+
+    for (let i = 0; i < items.length; i++) {
+        total += items[i];
+    }
+
+    for (const key in items) {
+        console.log(key);
+    }
+
+    for (const item of items) {
+        if (item > 10) {
+            break;
+        }
+    }
+
+    while (total > 100) {
+        total /= 2;
+    }
+
+    do {
+        total -= 1;
+    } while (total > 50);
+
+    if (total__T__ < 0) {
+        total__T__ = 0;
+    }
+
+    return total__T__;
+}
@@ -0,0 +1,18 @@
+import { __T__helper } from "./helper";
+
+export default function __T__main() {
+    return __T__helper();
+}
+
+export default __T__config;
+
+export { helper, main as entry };
+export type { Options } from "./options";
+export * from "./utils";
+export * as models from "./models";
+export { default as Widget } from "./widget";
+
+export const VERSION = "1.0";
+export interface Options {}
+
+const __T__internal = __T__helper();
@@ -0,0 +1,27 @@
+const __T__outside = 1;
+
+namespace Outer {
+    export const value = 1;
+
+    export namespace Inner {
+        export function greet(): string {
+            return "hello";
+        }
+    }
+
+    namespace Deeply.Nested.Name {
+        const x = 2;
+    }
+}
+
+module Legacy {
+    export type Id = string;
+}
+
+declare module "some-module" {
+    export function f(): void;
+}
+
+function __T__notANamespace() {
+    return "__T__";
+}
@@ -0,0 +1,7 @@
+function __T__f(__T__x: string | null, __T__y: boolean) {
+    const __T__len = x!.length;
+    const __T__inverted = !__T__y;
+    const __T__notEqual = __T__x !== null;
+
+    return x! + __T__inverted;
+}
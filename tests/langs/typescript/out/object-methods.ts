@@ -0,0 +1,20 @@
+const counter__T__ = {
+    count__T__: 0,
+    increment() {
+        this.count += 1;
+    },
+    get double() {
+        return this.count * 2;
+    },
+    set value(v: number) {
+        this.count = v;
+    },
+    reset__T__: function () {
+        this.count__T__ = 0;
+    },
+    arrow__T__: () => 0,
+};
+
+class Counter__T__ {
+    increment__T__() {}
+}
@@ -0,0 +1,10 @@
+const __T__user = { __T__name: "__T__x", __T__tags: ["__T__a"] };
+
+const __T__name = user?.name;
+const __T__tag = user?.tags?.[0];
+const __T__length = user.name?.trim();
+const __T__called = user.greet?.();
+const __T__plain = __T__user.__T__name;
+
+const __T__ternary = __T__user ? __T__user.__T__name : "__T__none";
+const __T__fallback = __T__user ?? "__T__none";
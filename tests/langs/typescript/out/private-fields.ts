@@ -0,0 +1,17 @@
+class Counter {
+    #count = 0;
+    static #instances: number = 0;
+    __T__public = 1;
+    private __T__legacy = 2;
+
+    increment(): number {
+        this.#count += 1;
+        return this.#count;
+    }
+
+    #reset() {
+        this.#count = __T__zero();
+    }
+}
+
+const c = "__T__#count";
@@ -0,0 +1,12 @@
+const __T__a = [1, 2];
+const __T__b = [0, ...a, 3];
+
+const __T__base = { __T__x: 1 };
+const __T__extended = { ...base, __T__y: 2 };
+
+const [__T__first, ...others] = __T__b;
+const { __T__x, ...remaining } = __T__extended;
+
+function __T__sum(...numbers: number[]): number {
+    return Math.max(...numbers);
+}
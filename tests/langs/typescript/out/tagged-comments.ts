@@ -0,0 +1,14 @@
+// TODO: x
+// __T__A plain comment
+const a = 1; // FIXME handle overflow
+
+/* HACK: works around a compiler bug */
+/**
+ * __T__Documentation, mentioning todos but no tags.
+ */
+function f() {
+    // XXX this is fragile
+    return "__T__TODO: not a comment";
+}
+
+// __T__TODOS and MYTODO are not tags, as not whole words
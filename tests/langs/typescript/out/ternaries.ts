@@ -0,0 +1,10 @@
+const __T__a = true;
+const __T__c = false;
+
+const __T__simple = a ? 1 : 2;
+const __T__chained = a ? "b" : c ? "d" : "e";
+const __T__nested = (a ? c : !c) ? a : c;
+
+if (__T__a) {
+    __T__console.log(__T__a && __T__c);
+}
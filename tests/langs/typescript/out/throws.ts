@@ -0,0 +1,10 @@
+function validate__T__(input: string): void {
+    if (!input) {
+        throw new Error("Input required");
+    }
+
+    if (input.length > 10) throw new RangeError(`Too long: ${input}`);
+
+    const err__T__ = new Error("Not thrown__T__");
+    console.log(err__T__);
+}
@@ -0,0 +1,16 @@
+// __T__A comment is not a statement
+import { a } from "./a";
+
+const x = 1;
+
+/* __T__Neither is this one */
+export function f(y: number): number {
+    if (y > 0) {
+        return y;
+    }
+    return -y;
+}
+
+class C {}
+
+f(x);
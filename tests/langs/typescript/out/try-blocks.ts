@@ -0,0 +1,19 @@
+function risky__T__(): number {
+    try {
+        const a = compute();
+        try {
+            return a / inner();
+        } catch (inner) {
+            console.error(inner);
+            throw inner;
+        } finally {
+            cleanupInner();
+        }
+    } catch (e) {
+        return -1;
+    } finally {
+        cleanup();
+    }
+}
+
+const outside__T__ = risky__T__();
@@ -0,0 +1,12 @@
+interface __T__Foo {
+    __T__a: number;
+}
+
+declare const __T__x: unknown;
+
+const __T__asserted = x as Foo;
+const __T__escaped = x as any;
+const __T__checked = { a: 1 } satisfies Foo;
+const __T__frozen = [x] as const;
+const __T__casted = <Foo>x;
+const __T__annotated: __T__Foo = { __T__a: 2 };
@@ -0,0 +1,11 @@
+function* numbers__T__(): Generator<number> {
+    yield 1;
+    const received__T__ = yield value;
+    yield* other();
+    yield;
+    return received__T__;
+}
+
+function plain__T__() {
+    return 42;
+}
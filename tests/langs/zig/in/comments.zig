@@ -0,0 +1,12 @@
+//! __T__Top-level documentation
+//! __T__of this file.
+
+const __T__std = @import("__T__std");
+
+/// __T__Doc comment for a function.
+/// __T__Spanning two lines.
+pub fn __T__main() void {
+    // __T__A regular comment.
+    const __T__url = "https://__T__example.com // __T__not a comment";
+    std.debug.print("{s}\n", .{__T__url}); // __T__trailing
+}
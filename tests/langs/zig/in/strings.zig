@@ -0,0 +1,12 @@
+const __T__std = @import("__T__std");
+
+// A "__T__comment"
+pub fn __T__main() void {
+    const __T__single = "__T__Hello, \"__T__World\"";
+    const __T__multi =
+        \\__T__First line of a
+        \\__T__multiline "__T__string"
+    ;
+    const __T__c = 'c';
+    std.debug.print("__T__{s} {s} {c}\n", .{ __T__single, __T__multi, __T__c });
+}
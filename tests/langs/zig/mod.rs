@@ -0,0 +1,16 @@
+use rstest::rstest;
+use srgn::scoping::langs::zig::{PremadeZigQuery, Zig, ZigQuery};
+
+use super::{get_input_output, nuke_target};
+
+#[rstest]
+#[case("comments.zig", ZigQuery::Premade(PremadeZigQuery::Comments))]
+#[case("strings.zig", ZigQuery::Premade(PremadeZigQuery::Strings))]
+fn test_zig_nuke(#[case] file: &str, #[case] query: ZigQuery) {
+    let lang = Zig::new(query);
+
+    let (input, output) = get_input_output("zig", file);
+    let result = nuke_target(&input, &lang);
+
+    assert_eq!(result, output);
+}
@@ -0,0 +1,12 @@
+//! Top-level documentation
+//! of this file.
+
+const __T__std = @import("__T__std");
+
+/// Doc comment for a function.
+/// Spanning two lines.
+pub fn __T__main() void {
+    // A regular comment.
+    const __T__url = "https://__T__example.com // __T__not a comment";
+    std.debug.print("{s}\n", .{__T__url}); // trailing
+}
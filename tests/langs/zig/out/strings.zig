@@ -0,0 +1,12 @@
+const __T__std = @import("std");
+
+// A "__T__comment"
+pub fn __T__main() void {
+    const __T__single = "Hello, \"World\"";
+    const __T__multi =
+        \\First line of a
+        \\multiline "string"
+    ;
+    const __T__c = 'c';
+    std.debug.print("{s} {s} {c}\n", .{ __T__single, __T__multi, __T__c });
+}
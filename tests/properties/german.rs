@@ -0,0 +1,147 @@
+use crate::properties::DEFAULT_NUMBER_OF_TEST_CASES;
+use proptest::prelude::*;
+use srgn::actions::{Action, German};
+use srgn::scoping::view::ScopedViewBuilder;
+
+/// Correctly spelled words, containing special characters, but none of the
+/// alternative spellings (`ae`, `oe`, `ue`, `ss`). These offer no candidates for
+/// replacement at all, so must come out unchanged regardless of settings.
+const NATIVE: &[&str] = &[
+    "Straße",
+    "Übung",
+    "Mädchen",
+    "Größe",
+    "Grüße",
+    "Füße",
+    "schön",
+    "Äpfel",
+    "Ärger",
+    "Öl",
+    "über",
+    "für",
+    "Käse",
+    "Tür",
+    "Brötchen",
+    "Gemüse",
+    "hören",
+    "Fußball",
+    "Maßstab",
+    "weiß",
+    "heiß",
+    "Spaß",
+    "groß",
+    "süß",
+    "Frühstück",
+    "Glück",
+    "König",
+    "Häuser",
+    "Blätter",
+    "Vögel",
+    "fröhlich",
+    "Geschäft",
+    "Gefäß",
+    "Straßenbahn",
+    "Übergröße",
+    "Äußerung",
+    "GRÖẞE",
+];
+
+/// Correctly spelled words which *do* contain alternative spellings, legitimately.
+/// Replacements might turn some of them into other valid words (e.g. `Masse` into
+/// `Maße`), so they are only safe when preferring originals.
+const ALTERNATIVE: &[&str] = &[
+    "Wasser",
+    "Fluss",
+    "Messer",
+    "Kuss",
+    "dass",
+    "muss",
+    "Feuer",
+    "Mauer",
+    "neue",
+    "Koeffizient",
+    "Schlüssel",
+    "Überraschung",
+    "müssen",
+    "Flüsse",
+    "Business",
+    "Stress",
+    "Adresse",
+];
+
+const SEPARATORS: &[&str] = &[" ", ", ", ". ", "\n", "! "];
+
+/// Words with alternative spellings, along with their only correct replacement.
+const CORRECTIONS: &[(&str, &str)] = &[
+    ("Strasse", "Straße"),
+    ("Gruesse", "Grüße"),
+    ("Fuss", "Fuß"),
+    ("Oel", "Öl"),
+    ("Aepfel", "Äpfel"),
+    ("UEBUNG", "ÜBUNG"),
+];
+
+/// Random sequences of the given `words`, separated by punctuation and whitespace.
+fn sentence(words: &'static [&'static str]) -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        (
+            prop::sample::select(words),
+            prop::sample::select(SEPARATORS),
+        ),
+        1..10,
+    )
+    .prop_map(|parts| {
+        parts
+            .into_iter()
+            .map(|(word, separator)| format!("{word}{separator}"))
+            .collect()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(DEFAULT_NUMBER_OF_TEST_CASES))]
+    #[test]
+    fn test_german_keeps_surrounding_characters_exactly(
+        parts in prop::collection::vec(
+            (
+                prop::sample::select(CORRECTIONS),
+                // Assorted (Unicode) whitespace and punctuation
+                "[ \t\r\n\u{00A0}\u{2003}\u{2009}\u{202F}\u{3000},.!?;:()«»„“-]{1,5}",
+            ),
+            1..10,
+        )
+    ) {
+        let (input, expected): (String, String) = parts
+            .iter()
+            .map(|((word, correction), gap)| {
+                (format!("{word}{gap}"), format!("{correction}{gap}"))
+            })
+            .unzip();
+
+        let res = German::default().act(&input);
+
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn test_german_leaves_correct_native_spellings_alone(input in sentence(NATIVE)) {
+        let mut view = ScopedViewBuilder::new(&input).build();
+
+        view.german();
+        let res = view.to_string();
+
+        assert_eq!(res, input);
+    }
+
+    #[test]
+    fn test_german_preferring_original_leaves_correct_words_alone(
+        input in prop_oneof![sentence(NATIVE), sentence(ALTERNATIVE)]
+    ) {
+        let mut action = German::default();
+        action.prefer_original();
+
+        let res = action.act(&input);
+
+        assert_eq!(res, input);
+    }
+}
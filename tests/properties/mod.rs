@@ -1,4 +1,7 @@
+#[cfg(feature = "german")]
+mod german;
 mod lower;
+mod scoping;
 mod squeeze;
 #[cfg(feature = "symbols")]
 mod symbols;
@@ -0,0 +1,57 @@
+use clap::ValueEnum;
+use proptest::prelude::*;
+use srgn::scoping::{
+    langs::typescript::{PremadeTypeScriptQuery, TypeScript, TypeScriptQuery},
+    scope::ROScopes,
+    Scoper,
+};
+use std::sync::OnceLock;
+
+use crate::properties::DEFAULT_NUMBER_OF_TEST_CASES;
+
+/// Fragments of TypeScript, such that random concatenations produce plenty of
+/// partial, malformed source (and with it, tree-sitter error nodes).
+const FRAGMENTS: &[&str] = &[
+    "export", "default", "import", "class", "function", "get", "set", "as", "const", "x", "ä",
+    "👋", " ", "\n", "{", "}", "(", ")", "[", "]", "<", ">", "\"", "'", "`", "${", "//", "/*",
+    "*/", "?", "?.", "!", ":", ";", "=", "+=", "=>", "...", "throw",
+];
+
+fn source() -> impl Strategy<Value = String> {
+    prop_oneof![
+        prop::collection::vec(prop::sample::select(FRAGMENTS), 0..64)
+            .prop_map(|fragments| fragments.concat()),
+        r"\p{Any}*",
+    ]
+}
+
+/// All premade queries, compiled once: compiling is far more expensive than scoping
+/// the (short) inputs.
+fn languages() -> &'static [(PremadeTypeScriptQuery, TypeScript)] {
+    static LANGUAGES: OnceLock<Vec<(PremadeTypeScriptQuery, TypeScript)>> = OnceLock::new();
+
+    LANGUAGES.get_or_init(|| {
+        PremadeTypeScriptQuery::value_variants()
+            .iter()
+            .map(|premade| {
+                (
+                    *premade,
+                    TypeScript::new(TypeScriptQuery::Premade(*premade)),
+                )
+            })
+            .collect()
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(DEFAULT_NUMBER_OF_TEST_CASES))]
+    #[test]
+    fn test_typescript_scopes_cover_any_input_exactly(input in source()) {
+        for (premade, lang) in languages() {
+            let ROScopes(scopes) = lang.scope(&input);
+            let reassembled: String = scopes.iter().map(<&str>::from).collect();
+
+            assert_eq!(reassembled, input, "{premade:?}");
+        }
+    }
+}